@@ -1,49 +0,0 @@
-#[allow(dead_code)]
-pub mod day1;
-#[allow(dead_code)]
-pub mod day10;
-#[allow(dead_code)]
-pub mod day11;
-#[allow(dead_code)]
-pub mod day12;
-#[allow(dead_code)]
-pub mod day13;
-#[allow(dead_code)]
-pub mod day14;
-#[allow(dead_code)]
-pub mod day15;
-#[allow(dead_code)]
-pub mod day16;
-#[allow(dead_code)]
-pub mod day17;
-#[allow(dead_code)]
-pub mod day18;
-#[allow(dead_code)]
-pub mod day19;
-#[allow(dead_code)]
-pub mod day2;
-#[allow(dead_code)]
-pub mod day20;
-#[allow(dead_code)]
-pub mod day21;
-#[allow(dead_code)]
-pub mod day22;
-#[allow(dead_code)]
-pub mod day23;
-#[allow(dead_code)]
-pub mod day24;
-pub mod day25;
-#[allow(dead_code)]
-pub mod day3;
-#[allow(dead_code)]
-pub mod day4;
-#[allow(dead_code)]
-pub mod day5;
-#[allow(dead_code)]
-pub mod day6;
-#[allow(dead_code)]
-pub mod day7;
-#[allow(dead_code)]
-pub mod day8;
-#[allow(dead_code)]
-pub mod day9;
@@ -0,0 +1,26 @@
+#![cfg_attr(feature = "nightly", feature(map_try_insert))]
+#![cfg_attr(feature = "nightly", feature(iter_map_windows))]
+//! Advent of Code, as a library; 2023 for now, other years alongside.
+//!
+//! Each `years::yYYYY::dayN` exposes `solve(input)`, giving the answers as
+//! `utils::report::Outcome`s, its parsed types and the solvers over them:
+//! the day10, day17 and day23 grids, the day5 and day19 ranges, the day22
+//! and day25 graphs, day1's `Vocabulary`, day2's `Game`, day12's `Record`,
+//! day15's `HolidayHashMap`, day24's `throw_rock`, ... Generators and
+//! animations stay inside the crate. Every year has its registries by day,
+//! `years::solver` and `years::generator` pick them by (year, day). Inputs
+//! live under `days/YYYY/dayN/`.
+//!
+//! Shared pieces: `years::y2023::day8::lcm`, `crt_pair` and `extended_gcd`,
+//! `utils::polynomial` (`difference_rows`, what used to be day9's
+//! `get_vectors`, and `Polynomial`) and `utils::input_process` for reading
+//! inputs.
+//!
+//! Builds on stable through `utils::compat`; the `nightly` feature switches
+//! to the native nightly APIs.
+//!
+//! The binary is only a command line over this.
+pub mod reference;
+pub mod utils;
+#[allow(unstable_name_collisions)]
+pub mod years;
@@ -1,35 +1,330 @@
-#![feature(get_many_mut)]
-#![feature(map_try_insert)]
-#![feature(iter_map_windows)]
-#![feature(half_open_range_patterns_in_slices)]
-#[allow(unstable_name_collisions)]
-mod days;
-mod utils;
+use aoc2023::utils::generate::{seeded, Knobs};
+use aoc2023::utils::render::Color;
+use aoc2023::utils::report::{self, Format, Outcome};
+use aoc2023::years::y2023::day1::{self, Vocabulary};
+use aoc2023::years::y2023::day15;
+use aoc2023::{reference, utils, years};
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::Path;
+
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write a random, valid input for a day
+    Gen {
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = years::DEFAULT_YEAR)]
+        year: u32,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Scale of the input, its meaning depends on the day
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        difficulty: usize,
+        /// Directory for input.txt, stdout when missing
+        #[arg(long)]
+        output: Option<String>,
+        /// Also write the answers known from the construction
+        #[arg(long)]
+        answer: bool,
+    },
+    /// Compare a day's solver with its brute-force reference on random inputs
+    Check {
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = years::DEFAULT_YEAR)]
+        year: u32,
+        #[arg(long, default_value_t = 200)]
+        cases: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Solve days and report their answers, checked against any answer.txt
+    /// next to the input
+    Run {
+        /// Every day of the year when missing
+        #[arg(long)]
+        day: Option<u32>,
+        #[arg(long, default_value_t = years::DEFAULT_YEAR)]
+        year: u32,
+        /// Instead of days/YYYY/dayN/input.txt, for a single day
+        #[arg(long)]
+        input: Option<String>,
+        /// Solve the example fixtures instead of the input
+        #[arg(long)]
+        examples: bool,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// 2023 day 1 number words, "word value" lines, one file per locale;
+        /// the words_*.txt of its data directory when missing
+        #[arg(long)]
+        vocabulary: Vec<String>,
+        /// 2023 day 15 boxes after each step of part two, on stderr
+        #[arg(long)]
+        trace: bool,
+    },
+    /// Write the examples of the descriptions as fixtures, with their answers
+    Examples {
+        /// Every day of the year when missing
+        #[arg(long)]
+        day: Option<u32>,
+        #[arg(long, default_value_t = years::DEFAULT_YEAR)]
+        year: u32,
+    },
+    /// Write a day's solving as numbered PPM frames
+    Render {
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = years::DEFAULT_YEAR)]
+        year: u32,
+        /// Instead of days/YYYY/dayN/input.txt
+        #[arg(long)]
+        input: Option<String>,
+        /// Directory for the frames
+        #[arg(long)]
+        out: String,
+        /// Pixels per cell, the day's own when missing
+        #[arg(long)]
+        scale: Option<usize>,
+        /// C=RRGGBB or default=RRGGBB, replacing the day's colors
+        #[arg(long)]
+        palette: Vec<Color>,
+    },
+    /// Browse a day's solving step by step in the terminal
+    Visualize {
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = years::DEFAULT_YEAR)]
+        year: u32,
+        /// Instead of days/YYYY/dayN/input.txt
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Start a day: its module, registered, and its data directory
+    New {
+        #[arg(long)]
+        day: u32,
+        /// A new year gets its module and registries too
+        #[arg(long, default_value_t = years::DEFAULT_YEAR)]
+        year: u32,
+    },
+}
+
+fn generate(year: u32, day: u32, seed: u64, knobs: Knobs, output: Option<String>, answer: bool) {
+    let generator =
+        years::generator(year, day).unwrap_or_else(|| panic!("Err: no day {day} in {year}"));
+    let generated = generator(&mut seeded(seed), &knobs);
+    match output {
+        Some(directory) => generated
+            .write(&directory, answer)
+            .expect("Err: could not write input"),
+        None => {
+            for line in &generated.lines {
+                println!("{line}");
+            }
+            if answer {
+                for line in generated.answer_lines() {
+                    eprintln!("{line}");
+                }
+            }
+        }
+    }
+}
+
+// The shrunk input is printed as is, ready for an input.txt.
+fn check(year: u32, day: u32, cases: usize, seed: u64) {
+    let checker = reference::checker(year, day)
+        .unwrap_or_else(|| panic!("Err: no reference for day {day} in {year}"));
+    match checker(cases, seed) {
+        Ok(passed) => println!("{year}/day{day}: {passed} cases passed"),
+        Err(counterexample) => {
+            println!(
+                "{year}/day{day}: case {} failed, shrunk {} times: {}",
+                counterexample.index, counterexample.shrinks, counterexample.message
+            );
+            for line in &counterexample.case {
+                println!("{line}");
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
+fn inputs(year: u32, day: u32, input: &Option<String>, examples: bool) -> Vec<String> {
+    let data = years::data_path(year, day);
+    match (input, examples) {
+        (Some(input), _) => vec![input.clone()],
+        (None, false) => vec![years::input_path(year, day)],
+        (None, true) => {
+            let Ok(entries) = fs::read_dir(format!("{data}/examples")) else {
+                return vec![];
+            };
+            let mut fixtures = entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|name| name.parse::<usize>().ok())
+                .collect::<Vec<_>>();
+            fixtures.sort();
+            fixtures
+                .iter()
+                .map(|k| format!("{data}/examples/{k}/input.txt"))
+                .collect()
+        }
+    }
+}
+
+// False when any answer is wrong or missing.
+fn solve(
+    year: u32,
+    days: Vec<u32>,
+    input: Option<String>,
+    examples: bool,
+    format: Format,
+    vocabularies: &[Vocabulary],
+    trace: bool,
+) -> bool {
+    let mut outcomes: Vec<Outcome> = vec![];
+    for day in days {
+        let solver =
+            years::solver(year, day).unwrap_or_else(|| panic!("Err: no day {day} in {year}"));
+        for input in inputs(year, day, &input, examples) {
+            let answer = Path::new(&input).with_file_name("answer.txt");
+            let answer_lines = fs::read_to_string(answer)
+                .map(|answers| answers.lines().map(|line| line.to_string()).collect())
+                .unwrap_or_else(|_| vec![]);
+            let solved = match (year, day) {
+                (2023, 1) if !vocabularies.is_empty() => day1::solve_with(&input, vocabularies),
+                (2023, 15) if trace => day15::solve_with(&input, true),
+                _ => solver(&input),
+            };
+            for mut outcome in solved {
+                outcome.check(&answer_lines);
+                outcomes.push(outcome);
+            }
+        }
+    }
+    report::print(&outcomes, format);
+    !outcomes.iter().any(Outcome::is_mismatch)
+}
 
 fn main() {
-    // days::day1::run();
-    // days::day2::run();
-    // days::day3::run();
-    // days::day4::run();
-    // days::day5::run();
-    // days::day6::run();
-    // days::day7::run();
-    // days::day8::run();
-    // days::day9::run();
-    // days::day10::run();
-    // days::day11::run();
-    // days::day12::run();
-    // days::day13::run();
-    // days::day14::run();
-    // days::day15::run();
-    // days::day16::run();
-    // days::day17::run();
-    // days::day18::run();
-    // days::day19::run();
-    // days::day20::run();
-    // days::day21::run();
-    // days::day22::run();
-    // days::day23::run();
-    // days::day24::run();
-    days::day25::run();
+    match Cli::parse().command {
+        Some(Command::Gen {
+            day,
+            year,
+            seed,
+            size,
+            difficulty,
+            output,
+            answer,
+        }) => {
+            let knobs = Knobs { size, difficulty };
+            generate(year, day, seed, knobs, output, answer);
+            return;
+        }
+        Some(Command::Check {
+            day,
+            year,
+            cases,
+            seed,
+        }) => {
+            check(year, day, cases, seed);
+            return;
+        }
+        Some(Command::Run {
+            day,
+            year,
+            input,
+            examples,
+            format,
+            vocabulary,
+            trace,
+        }) => {
+            let days = day.map_or(years::days(year), |day| vec![day]);
+            let vocabularies = vocabulary
+                .iter()
+                .map(|path| Vocabulary::from_file(path).expect("Err: could not read vocabulary"))
+                .collect::<Vec<_>>();
+            if !solve(year, days, input, examples, format, &vocabularies, trace) {
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Examples { day, year }) => {
+            for day in day.map_or(years::days(year), |day| vec![day]) {
+                let written = utils::examples::write_fixtures(year, day);
+                println!("{year}/day{day}: {written} examples");
+            }
+            return;
+        }
+        Some(Command::Render {
+            day,
+            year,
+            input,
+            out,
+            scale,
+            palette,
+        }) => {
+            let animate = years::animator(year, day)
+                .unwrap_or_else(|| panic!("Err: day {day} of {year} does not render"));
+            let input = input.unwrap_or(years::input_path(year, day));
+            animate(&input, &out, &palette, scale);
+            return;
+        }
+        Some(Command::Visualize { day, year, input }) => {
+            let visualize = years::visualizer(year, day)
+                .unwrap_or_else(|| panic!("Err: day {day} of {year} has no visualization"));
+            let input = input.unwrap_or(years::input_path(year, day));
+            visualize(&input);
+            return;
+        }
+        Some(Command::New { day, year }) => {
+            match utils::scaffold::new_day(year, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("{path}");
+                    }
+                }
+                Err(message) => {
+                    eprintln!("Err: {message}");
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        None => (),
+    }
+
+    // years::y2023::day1::run();
+    // years::y2023::day2::run();
+    // years::y2023::day3::run();
+    // years::y2023::day4::run();
+    // years::y2023::day5::run();
+    // years::y2023::day6::run();
+    // years::y2023::day7::run();
+    // years::y2023::day8::run();
+    // years::y2023::day9::run();
+    // years::y2023::day10::run();
+    // years::y2023::day11::run();
+    // years::y2023::day12::run();
+    // years::y2023::day13::run();
+    // years::y2023::day14::run();
+    // years::y2023::day15::run();
+    // years::y2023::day16::run();
+    // years::y2023::day17::run();
+    // years::y2023::day18::run();
+    // years::y2023::day19::run();
+    // years::y2023::day20::run();
+    // years::y2023::day21::run();
+    // years::y2023::day22::run();
+    // years::y2023::day23::run();
+    // years::y2023::day24::run();
+    years::y2023::day25::run();
 }
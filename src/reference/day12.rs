@@ -0,0 +1,132 @@
+use crate::utils::property::{
+    check as check_property, simplify_each, without_each, Counterexample,
+};
+use crate::years::y2023::day12::{process_lines, Unfolding};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Past this many '?', enumerating is not worth it.
+const MOST_UNKNOWNS: usize = 16;
+
+fn runs(springs: &[u8]) -> Vec<usize> {
+    springs
+        .split(|ch| *ch == b'.')
+        .filter(|run| !run.is_empty())
+        .map(|run| run.len())
+        .collect()
+}
+
+// Every assignment of the '?', kept when its runs of '#' are the groups.
+pub fn arrangements(line: &str, unfolding: &Unfolding) -> Option<u128> {
+    let (springs, groups) = line.split_once(' ').expect("Err: wrong input shape");
+    let springs = vec![springs; unfolding.factor].join(&unfolding.separator.to_string());
+    let groups = vec![groups; unfolding.factor]
+        .join(",")
+        .split(',')
+        .filter(|group| !group.is_empty())
+        .map(|group| group.parse::<usize>().expect("Err: not a number"))
+        .collect_vec();
+
+    let unknowns = springs.match_indices('?').map(|(p, _)| p).collect_vec();
+    if unknowns.len() > MOST_UNKNOWNS {
+        return None;
+    }
+
+    let mut cells = springs.into_bytes();
+    let count = (0..1_u32 << unknowns.len())
+        .filter(|mask| {
+            for (bit, p) in unknowns.iter().enumerate() {
+                cells[*p] = if mask >> bit & 1 == 1 { b'#' } else { b'.' };
+            }
+            runs(&cells) == groups
+        })
+        .count();
+    Some(count as u128)
+}
+
+fn random_record(rng: &mut StdRng) -> String {
+    let springs = (0..rng.gen_range(1..=8))
+        .map(|_| ['?', '.', '#'][rng.gen_range(0..3)])
+        .collect::<String>();
+    let groups = (0..rng.gen_range(1..=3))
+        .map(|_| rng.gen_range(1..=3))
+        .join(",");
+    format!("{springs} {groups}")
+}
+
+// One spring or one group less, a known spring made operational, or a
+// group made shorter.
+fn simpler_record(line: &str) -> Vec<String> {
+    let (springs, groups) = line.split_once(' ').expect("Err: wrong input shape");
+    let groups = groups.split(',').collect_vec();
+    let record = |springs: &str, groups: &[String]| format!("{springs} {}", groups.join(","));
+    let groups_owned = groups.iter().map(|group| group.to_string()).collect_vec();
+
+    let mut simpler = vec![];
+    for p in 0..springs.len() {
+        if springs.len() > 1 {
+            let shorter = [&springs[..p], &springs[p + 1..]].concat();
+            simpler.push(record(&shorter, &groups_owned));
+        }
+        if &springs[p..p + 1] != "." {
+            let operational = [&springs[..p], ".", &springs[p + 1..]].concat();
+            simpler.push(record(&operational, &groups_owned));
+        }
+    }
+    if groups.len() > 1 {
+        for fewer in without_each(&groups_owned) {
+            simpler.push(record(springs, &fewer));
+        }
+    }
+    for shorter in simplify_each(&groups_owned, |group| match group.as_str() {
+        "1" => vec![],
+        group => {
+            let group = group.parse::<usize>().expect("Err: not a number");
+            vec![(group - 1).to_string()]
+        }
+    }) {
+        simpler.push(record(springs, &shorter));
+    }
+    simpler
+}
+
+// The NFA against enumeration, folded once and twice.
+fn property(lines: &[String]) -> Result<(), String> {
+    for factor in [1, 2] {
+        let unfolding = Unfolding::new(factor, '?');
+        for line in lines {
+            let Some(expected) = arrangements(line, &unfolding) else {
+                continue;
+            };
+            let found = process_lines(std::slice::from_ref(line), &unfolding);
+            if found != expected {
+                return Err(format!(
+                    "{line:?} unfolded {factor} times: {found} arrangements, expected {expected}"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn check(cases: usize, seed: u64) -> Result<usize, Counterexample<Vec<String>>> {
+    check_property(
+        cases,
+        seed,
+        |rng| {
+            (0..rng.gen_range(1..=4))
+                .map(|_| random_record(rng))
+                .collect::<Vec<String>>()
+        },
+        |lines| {
+            let mut candidates = match lines.len() {
+                1 => vec![],
+                _ => without_each(lines),
+            };
+            candidates.extend(simplify_each(lines, |line: &String| simpler_record(line)));
+            candidates
+        },
+        |lines| property(lines),
+    )
+}
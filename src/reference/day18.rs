@@ -0,0 +1,177 @@
+use crate::utils::generate::Knobs;
+use crate::utils::property::{check as check_property, without_each, Counterexample};
+use crate::years::y2023::day18::{generate, process_input, Encoding};
+use itertools::Itertools;
+use rand::Rng;
+use std::collections::{HashSet, VecDeque};
+
+fn steps(lines: &[String]) -> Vec<(char, i64)> {
+    lines
+        .iter()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let direction = parts
+                .next()
+                .and_then(|direction| direction.chars().next())
+                .expect("Err: no direction");
+            let len = parts
+                .next()
+                .expect("Err: no length")
+                .parse::<i64>()
+                .expect("Err: not a number");
+            (direction, len)
+        })
+        .collect()
+}
+
+// (#70c710): 0x70c71 to the right, the last digit going R, D, L, U.
+fn hex_steps(lines: &[String]) -> Vec<(char, i64)> {
+    lines
+        .iter()
+        .map(|line| {
+            let (_, color) = line.split_once("(#").expect("Err: no color");
+            let digits = color.trim_end_matches(')');
+            let len = i64::from_str_radix(&digits[..5], 16).expect("Err: not hex");
+            let direction = match &digits[5..] {
+                "0" => 'R',
+                "1" => 'D',
+                "2" => 'L',
+                "3" => 'U',
+                other => panic!("Err: unknown direction digit {other}"),
+            };
+            (direction, len)
+        })
+        .collect()
+}
+
+// Digs the trench cell by cell, floods the outside from a corner of the
+// bounding box; whatever the water does not reach is the lagoon.
+pub fn flooded(steps: &[(char, i64)]) -> usize {
+    let mut trench = HashSet::from([(0_i64, 0_i64)]);
+    let (mut i, mut j) = (0, 0);
+    for (direction, len) in steps.iter().copied() {
+        let (di, dj) = match direction {
+            'R' => (0, 1),
+            'D' => (1, 0),
+            'L' => (0, -1),
+            'U' => (-1, 0),
+            _ => panic!("Err: unknown direction {direction}"),
+        };
+        for _ in 0..len {
+            (i, j) = (i + di, j + dj);
+            trench.insert((i, j));
+        }
+    }
+
+    let (top, bottom) = trench
+        .iter()
+        .map(|(i, _)| *i)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (left, right) = trench
+        .iter()
+        .map(|(_, j)| *j)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (top, bottom, left, right) = (top - 1, bottom + 1, left - 1, right + 1);
+
+    let mut outside = HashSet::from([(top, left)]);
+    let mut queue = VecDeque::from([(top, left)]);
+    while let Some((i, j)) = queue.pop_front() {
+        for next in [(i + 1, j), (i - 1, j), (i, j + 1), (i, j - 1)] {
+            let inside_box = (top..=bottom).contains(&next.0) && (left..=right).contains(&next.1);
+            if inside_box && !trench.contains(&next) && outside.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    ((bottom - top + 1) * (right - left + 1)) as usize - outside.len()
+}
+
+// Shortening two opposite steps together keeps the loop closed.
+fn shorter_pairs(lines: &[String]) -> Vec<Vec<String>> {
+    let steps = steps(lines);
+    let opposite =
+        |a: char, b: char| matches!((a, b), ('R', 'L') | ('L', 'R') | ('U', 'D') | ('D', 'U'));
+    let shorter = |line: &String, len: i64| {
+        let (direction, rest) = line.split_once(' ').expect("Err: wrong input shape");
+        let (_, color) = rest.split_once(' ').expect("Err: wrong input shape");
+        format!("{direction} {} {color}", len - 1)
+    };
+
+    let mut candidates = vec![];
+    for (a, b) in (0..steps.len()).tuple_combinations() {
+        let ((da, la), (db, lb)) = (steps[a], steps[b]);
+        if opposite(da, db) && la > 1 && lb > 1 {
+            let mut lines = lines.to_vec();
+            lines[a] = shorter(&lines[a], la);
+            lines[b] = shorter(&lines[b], lb);
+            candidates.push(lines);
+        }
+    }
+    candidates
+}
+
+// The generator's colors are far too big to flood, they get the plain loop
+// walked backwards instead: same lagoon, other steps.
+fn small_colors(lines: Vec<String>) -> Vec<String> {
+    let steps = steps(&lines);
+    let backwards = steps.iter().rev().map(|(direction, len)| {
+        let back = match direction {
+            'R' => 2,
+            'D' => 3,
+            'L' => 0,
+            _ => 1,
+        };
+        format!("#{len:05x}{back}")
+    });
+    lines
+        .iter()
+        .zip(backwards)
+        .map(|(line, color)| {
+            let (plain, _) = line.split_once(" (").expect("Err: no color");
+            format!("{plain} ({color})")
+        })
+        .collect()
+}
+
+// Only plans the shoelace accepts are compared, anything else is not a loop.
+fn property(lines: &[String]) -> Result<(), String> {
+    for (encoding, steps) in [
+        (Encoding::Plain, steps(lines)),
+        (Encoding::Hex, hex_steps(lines)),
+    ] {
+        let Ok(found) = process_input(lines, encoding) else {
+            continue;
+        };
+        let expected = flooded(&steps) as i128;
+        if found != expected {
+            return Err(format!(
+                "{encoding:?} lagoon of {found}, expected {expected}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn check(cases: usize, seed: u64) -> Result<usize, Counterexample<Vec<String>>> {
+    check_property(
+        cases,
+        seed,
+        |rng| {
+            let knobs = Knobs {
+                size: rng.gen_range(3..=8),
+                difficulty: rng.gen_range(0..=3),
+            };
+            small_colors(generate(rng, &knobs).lines)
+        },
+        |lines| {
+            let mut candidates = shorter_pairs(lines);
+            candidates.extend(without_each(lines));
+            candidates
+        },
+        |lines| property(lines),
+    )
+}
@@ -0,0 +1,84 @@
+use crate::utils::generate::Knobs;
+use crate::utils::property::{check as check_property, simplify_each, Counterexample};
+use crate::years::y2023::day21::{garden_steps, generate};
+use itertools::Itertools;
+use num_bigint::BigInt;
+use rand::Rng;
+use std::collections::{HashMap, VecDeque};
+
+// Plain BFS over the tiled garden. A plot can be stood on after exactly
+// `steps` when it is reached in no more, with the same parity.
+pub fn reachable(lines: &[String], steps: usize) -> usize {
+    let garden = lines.iter().map(|line| line.as_bytes()).collect::<Vec<_>>();
+    let side = garden.len() as i64;
+    let start = (0..side)
+        .flat_map(|i| (0..side).map(move |j| (i, j)))
+        .find(|(i, j)| garden[*i as usize][*j as usize] == b'S')
+        .expect("Err: no start");
+    let rock = |(i, j): (i64, i64)| {
+        garden[i.rem_euclid(side) as usize][j.rem_euclid(side) as usize] == b'#'
+    };
+
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some((i, j)) = queue.pop_front() {
+        let distance = distances[&(i, j)];
+        if distance == steps {
+            continue;
+        }
+        for next in [(i + 1, j), (i - 1, j), (i, j + 1), (i, j - 1)] {
+            if !rock(next) && !distances.contains_key(&next) {
+                distances.insert(next, distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+        .values()
+        .filter(|distance| *distance % 2 == steps % 2)
+        .count()
+}
+
+// Past the fitted samples too, that is the whole point of the fit.
+const REPEATS: [usize; 3] = [1, 4, 7];
+
+fn property(lines: &[String]) -> Result<(), String> {
+    // The fast solver wants its own Vec.
+    let garden = lines.to_vec();
+    let side = lines.len();
+    // On a garden border, and off it.
+    for (repeats, extra) in REPEATS.into_iter().cartesian_product([0, 1, side / 2 + 1]) {
+        let steps = side / 2 + repeats * side + extra;
+        let found = garden_steps(&garden, steps);
+        let expected = BigInt::from(reachable(lines, steps));
+        if found != expected {
+            return Err(format!(
+                "{found} plots after {steps} steps, expected {expected}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn check(cases: usize, seed: u64) -> Result<usize, Counterexample<Vec<String>>> {
+    check_property(
+        cases,
+        seed,
+        |rng| {
+            let knobs = Knobs {
+                size: rng.gen_range(2..=5),
+                difficulty: rng.gen_range(0..=3),
+            };
+            generate(rng, &knobs).lines
+        },
+        // One rock less.
+        |lines| {
+            simplify_each(lines, |line| {
+                line.match_indices('#')
+                    .map(|(p, _)| [&line[..p], ".", &line[p + 1..]].concat())
+                    .collect()
+            })
+        },
+        |lines| property(lines),
+    )
+}
@@ -0,0 +1,137 @@
+use crate::utils::property::{check as check_property, without_each, Counterexample};
+use crate::years::y2023::day24::{get_places, throw_rock, Throw, ThrowError};
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+use rand::Rng;
+
+// Every time searched, for the rock and for the generated stones.
+const LATEST: i64 = 12;
+
+type Stone = ([i64; 3], [i64; 3]);
+
+fn stones(lines: &[String]) -> Vec<Stone> {
+    let triple = |text: &str| {
+        text.split(',')
+            .map(|n| n.trim().parse::<i64>().expect("Err: not a number"))
+            .collect_vec()
+            .try_into()
+            .expect("Err: not three numbers")
+    };
+    lines
+        .iter()
+        .map(|line| {
+            let (position, velocity) = line.split_once('@').expect("Err: wrong input shape");
+            (triple(position), triple(velocity))
+        })
+        .collect()
+}
+
+fn at((position, velocity): &Stone, t: i64) -> [i64; 3] {
+    [0, 1, 2].map(|k| position[k] + velocity[k] * t)
+}
+
+fn meets(rock: &Stone, stone: &Stone) -> bool {
+    (1..=LATEST).any(|t| at(rock, t) == at(stone, t))
+}
+
+// Each pair of times for the first two stones fixes a rock; keep those
+// meeting every stone.
+pub fn rocks(lines: &[String]) -> Vec<Stone> {
+    let stones = stones(lines);
+    let mut rocks = vec![];
+    for (t0, t1) in (1..=LATEST).cartesian_product(1..=LATEST) {
+        if t0 == t1 {
+            continue;
+        }
+        let (a, b) = (at(&stones[0], t0), at(&stones[1], t1));
+        if (0..3).any(|k| (b[k] - a[k]) % (t1 - t0) != 0) {
+            continue;
+        }
+        let velocity = [0, 1, 2].map(|k| (b[k] - a[k]) / (t1 - t0));
+        let rock = ([0, 1, 2].map(|k| a[k] - velocity[k] * t0), velocity);
+        if stones.iter().all(|stone| meets(&rock, stone)) && !rocks.contains(&rock) {
+            rocks.push(rock);
+        }
+    }
+    rocks
+}
+
+// A small rock, hitting a few stones at different times.
+fn random_case(rng: &mut rand::rngs::StdRng) -> Vec<String> {
+    let rock: Stone = (
+        [0; 3].map(|_| rng.gen_range(-30..=30)),
+        [0; 3].map(|_| rng.gen_range(-4..=4)),
+    );
+    let mut times = (1..=LATEST).collect_vec();
+    (0..rng.gen_range(4..=6))
+        .map(|_| {
+            let t = times.remove(rng.gen_range(0..times.len()));
+            let mut velocity = rock.1;
+            while velocity == rock.1 {
+                velocity = [0; 3].map(|_| rng.gen_range(-4..=4));
+            }
+            let hit = at(&rock, t);
+            let position = [0, 1, 2].map(|k| hit[k] - velocity[k] * t);
+            format!(
+                "{}, {}, {} @ {}, {}, {}",
+                position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+            )
+        })
+        .collect()
+}
+
+// Ahead on the rock's path, at any rational time: the gap to the stone and
+// the closing speed point the same way.
+fn hits(throw: &Throw, (position, velocity): &Stone) -> bool {
+    let big = |value: i64| BigRational::from_integer(BigInt::from(value));
+    let gap = [0, 1, 2].map(|k| big(position[k]) - &throw.position[k]);
+    let closing = [0, 1, 2].map(|k| &throw.velocity[k] - big(velocity[k]));
+    if closing.iter().all(Zero::is_zero) {
+        return gap.iter().all(Zero::is_zero);
+    }
+    let parallel = (0..3)
+        .tuple_combinations()
+        .all(|(a, b)| &gap[a] * &closing[b] == &gap[b] * &closing[a]);
+    let ahead: BigRational = (0..3).map(|k| &gap[k] * &closing[k]).sum();
+    parallel && !ahead.is_negative()
+}
+
+// With several rocks fitting, any of them will do, and only then may the
+// system be singular.
+fn property(lines: &[String]) -> Result<(), String> {
+    let rocks = rocks(lines);
+    if rocks.is_empty() {
+        return Ok(());
+    }
+    let sums = rocks
+        .iter()
+        .map(|(position, _)| position.iter().sum::<i64>())
+        .join(", ");
+    match throw_rock(&get_places(lines)) {
+        Ok(throw) => match stones(lines).iter().position(|stone| !hits(&throw, stone)) {
+            None => Ok(()),
+            Some(i) => Err(format!(
+                "rock at {} misses stone {i}, expected one of {sums}",
+                throw.position.iter().join(", ")
+            )),
+        },
+        Err(ThrowError::Degenerate) if rocks.len() > 1 => Ok(()),
+        Err(err) => Err(format!("{err:?}, expected one of {sums}")),
+    }
+}
+
+pub fn check(cases: usize, seed: u64) -> Result<usize, Counterexample<Vec<String>>> {
+    check_property(
+        cases,
+        seed,
+        random_case,
+        // Three stones are the least that can fix a rock.
+        |lines| match lines.len() {
+            ..=4 => vec![],
+            _ => without_each(lines),
+        },
+        |lines| property(lines),
+    )
+}
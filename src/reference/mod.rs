@@ -0,0 +1,21 @@
+// Slow but obviously right solvers, checked against the fast ones of 2023.
+pub mod day12;
+pub mod day18;
+pub mod day21;
+pub mod day24;
+
+use crate::utils::property::Counterexample;
+
+// Cases are input lines, so a counterexample can go straight to a file.
+pub type Checker = fn(usize, u64) -> Result<usize, Counterexample<Vec<String>>>;
+
+pub fn checker(year: u32, day: u32) -> Option<Checker> {
+    let checker: Checker = match (year, day) {
+        (2023, 12) => day12::check,
+        (2023, 18) => day18::check,
+        (2023, 21) => day21::check,
+        (2023, 24) => day24::check,
+        _ => return None,
+    };
+    Some(checker)
+}
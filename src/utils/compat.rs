@@ -0,0 +1,37 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+// Stable stand-ins for the nightly APIs the days use. With the `nightly`
+// feature the native versions are used instead, the days do not import these
+// then.
+
+// Like HashMap::try_insert, the Err only gives the value back.
+pub trait TryInsert<K, V> {
+    fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, V>;
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> TryInsert<K, V> for HashMap<K, V, S> {
+    fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, V> {
+        match self.entry(key) {
+            Entry::Occupied(_) => Err(value),
+            Entry::Vacant(entry) => Ok(entry.insert(value)),
+        }
+    }
+}
+
+// Like Iterator::map_windows, but eager: the items are collected first.
+pub trait MapWindows: Iterator + Sized {
+    fn map_windows<const N: usize, R, F>(self, mut f: F) -> std::vec::IntoIter<R>
+    where
+        F: FnMut(&[Self::Item; N]) -> R,
+    {
+        let items = self.collect::<Vec<_>>();
+        items
+            .windows(N)
+            .map(|window| f(window.try_into().expect("Err: window of the wrong size")))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<I: Iterator> MapWindows for I {}
@@ -0,0 +1,342 @@
+use crate::utils::generate::Generated;
+use crate::years;
+use std::fs;
+
+// Sentences with these state a final result rather than some intermediate
+// value. Found by reading the descriptions, nothing clever.
+const RESULT_WORDS: [&str; 6] = [
+    "produces",
+    "sum",
+    "in total",
+    "in this example",
+    "lowest",
+    "steps",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Paragraph {
+    // Index of the part, 0 or 1.
+    Heading(usize),
+    // Lines joined back into one string.
+    Prose(String),
+    Code(Vec<String>),
+}
+
+// Answers stay None when the description does not state them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub lines: Vec<String>,
+    pub answers: [Option<i128>; 2],
+}
+
+impl Example {
+    // Same files as the generators write.
+    pub fn fixture(&self) -> Generated {
+        Generated::new(self.lines.clone()).with_answers(self.answers[0], self.answers[1])
+    }
+}
+
+fn heading(line: &str) -> Option<usize> {
+    let line = line.trim_start_matches('#').trim().to_lowercase();
+    let part = line.strip_prefix("part ")?.trim_end_matches(':');
+    match part {
+        "one" | "1" => Some(0),
+        "two" | "2" => Some(1),
+        _ => None,
+    }
+}
+
+// Indented bullets and links, from the first line.
+fn aside(line: &str) -> bool {
+    line.starts_with("    ") || line.starts_with("http")
+}
+
+// Input lines do not end like a sentence does.
+fn sentence_end(line: &str) -> bool {
+    let mut tail = line.chars().rev();
+    match (tail.next(), tail.next()) {
+        (Some('.' | ':' | '?' | '!'), Some(before)) => before.is_alphanumeric() || before == ')',
+        _ => false,
+    }
+}
+
+fn paragraphs(description: &str) -> Vec<Paragraph> {
+    let mut paragraphs: Vec<Paragraph> = vec![];
+    let lines = description.lines().collect::<Vec<_>>();
+    for group in lines.split(|line| line.trim().is_empty()) {
+        let paragraph = match group {
+            [] => continue,
+            [line] if heading(line).is_some() => Paragraph::Heading(heading(line).unwrap()),
+            [first, .., last] | [first @ last]
+                if aside(first)
+                    || (sentence_end(last) && group.iter().any(|line| line.contains(' '))) =>
+            {
+                Paragraph::Prose(
+                    group
+                        .iter()
+                        .map(|line| line.trim())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                )
+            }
+            _ => Paragraph::Code(group.iter().map(|line| line.to_string()).collect()),
+        };
+        // Inputs with blank lines in them (day5, day8) come in several pieces.
+        match (paragraphs.last_mut(), paragraph) {
+            (Some(Paragraph::Code(previous)), Paragraph::Code(lines)) => {
+                previous.push(String::new());
+                previous.extend(lines);
+            }
+            (_, paragraph) => paragraphs.push(paragraph),
+        }
+    }
+    paragraphs
+}
+
+// The last number of the last sentence stating a result.
+fn result(prose: &str) -> Option<i128> {
+    prose
+        .split(['.', '!', '?', ':'])
+        .filter(|sentence| {
+            let sentence = sentence.to_lowercase();
+            RESULT_WORDS.iter().any(|word| sentence.contains(word))
+        })
+        .filter_map(|sentence| {
+            sentence
+                .split(|ch: char| !ch.is_ascii_digit() && ch != '-')
+                .filter_map(|word| word.parse::<i128>().ok())
+                .next_back()
+        })
+        .next_back()
+}
+
+// Not every code block is an input: most are drawings of the solution. An
+// input is the first block of a part, or one announced as an example.
+fn inputs(paragraphs: &[Paragraph]) -> Vec<bool> {
+    let mut seen_code = false;
+    paragraphs
+        .iter()
+        .enumerate()
+        .map(|(k, paragraph)| match paragraph {
+            Paragraph::Heading(_) => {
+                seen_code = false;
+                false
+            }
+            Paragraph::Prose(_) => false,
+            Paragraph::Code(_) => {
+                let announced = match k.checked_sub(1).map(|k| &paragraphs[k]) {
+                    Some(Paragraph::Prose(text)) => text.to_lowercase().contains("example"),
+                    _ => false,
+                };
+                let first = !seen_code;
+                seen_code = true;
+                announced || first
+            }
+        })
+        .collect()
+}
+
+// Every input block of a description, with the answers stated after it (or
+// in the sentence announcing it), up to the next input. A block repeating
+// part of an earlier one adds its answers to it.
+pub fn examples(description: &str) -> Vec<Example> {
+    let paragraphs = paragraphs(description);
+    let inputs = inputs(&paragraphs);
+
+    let mut examples: Vec<Example> = vec![];
+    let mut part = 0;
+    let mut current = None;
+    for (k, paragraph) in paragraphs.iter().enumerate() {
+        match paragraph {
+            Paragraph::Heading(heading) => part = *heading,
+            Paragraph::Code(lines) if inputs[k] => {
+                let repeated = examples.iter().position(|example| {
+                    example
+                        .lines
+                        .windows(lines.len())
+                        .any(|window| window == lines.as_slice())
+                });
+                current = Some(repeated.unwrap_or_else(|| {
+                    examples.push(Example {
+                        lines: lines.clone(),
+                        answers: [None, None],
+                    });
+                    examples.len() - 1
+                }));
+                if let Some(Paragraph::Prose(text)) = k.checked_sub(1).map(|k| &paragraphs[k]) {
+                    if let Some(answer) = result(text) {
+                        examples[current.unwrap()].answers[part] = Some(answer);
+                    }
+                }
+            }
+            Paragraph::Code(_) => (),
+            Paragraph::Prose(text) => {
+                // That one is for the next input.
+                if inputs.get(k + 1) == Some(&true) {
+                    continue;
+                }
+                if let (Some(current), Some(answer)) = (current, result(text)) {
+                    examples[current].answers[part] = Some(answer);
+                }
+            }
+        }
+    }
+
+    examples
+        .into_iter()
+        .filter(|example| example.answers.iter().any(|answer| answer.is_some()))
+        .collect()
+}
+
+// Both answers of an answer.txt, None when unknown.
+fn known_answers(answers: &str) -> [Option<i128>; 2] {
+    let mut known = [None, None];
+    for (part, answer) in answers.lines().filter_map(|line| line.split_once(' ')) {
+        match part {
+            "part_one" => known[0] = answer.parse().ok(),
+            "part_two" => known[1] = answer.parse().ok(),
+            _ => (),
+        }
+    }
+    known
+}
+
+// The fixtures already written, as K, input lines and answers.
+fn fixtures(directory: &str) -> Vec<(usize, Vec<String>, [Option<i128>; 2])> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok()?.parse().ok())
+        .filter_map(|k: usize| {
+            let input = fs::read_to_string(format!("{directory}/{k}/input.txt")).ok()?;
+            let answers =
+                fs::read_to_string(format!("{directory}/{k}/answer.txt")).unwrap_or_default();
+            let lines = input.lines().map(|line| line.to_string()).collect();
+            Some((k, lines, known_answers(&answers)))
+        })
+        .collect()
+}
+
+// days/YYYY/dayN/examples/K/, input.txt and answer.txt, K from 1. A fixture
+// with the same input is rewritten in place, keeping the answers the
+// description does not state: the descriptions are notes, most answers go in
+// by hand.
+pub fn write_fixtures(year: u32, day: u32) -> usize {
+    let data = years::data_path(year, day);
+    let description = format!("{data}/description.txt");
+    let Ok(description) = fs::read_to_string(description) else {
+        return 0;
+    };
+    let directory = format!("{data}/examples");
+    let mut fixtures = fixtures(&directory);
+    let examples = examples(&description);
+    for example in examples.iter() {
+        let (k, known) = match fixtures
+            .iter()
+            .find(|(_, lines, _)| *lines == example.lines)
+        {
+            Some((k, _, known)) => (*k, *known),
+            None => {
+                let k = fixtures.iter().map(|(k, _, _)| *k).max().unwrap_or(0) + 1;
+                fixtures.push((k, example.lines.clone(), [None, None]));
+                (k, [None, None])
+            }
+        };
+        let merged = Example {
+            lines: example.lines.clone(),
+            answers: [0, 1].map(|part| example.answers[part].or(known[part])),
+        };
+        merged
+            .fixture()
+            .write(&format!("{directory}/{k}"), true)
+            .expect("Err: could not write fixture");
+    }
+    examples.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTION: &str = "https://adventofcode.com/2023/day/99
+
+# Part one
+
+For example:
+
+1 2
+3 4
+
+In this example, the sum is 10.
+
+# Part two
+
+Now the product of everything in total is 24.
+
+Here is another example:
+
+5 6
+
+This one gives 30 in total.
+";
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    // Part two answers the part one input too, then brings its own.
+    #[test]
+    fn answers_by_part() {
+        assert_eq!(
+            examples(DESCRIPTION),
+            [
+                Example {
+                    lines: lines(&["1 2", "3 4"]),
+                    answers: [Some(10), Some(24)],
+                },
+                Example {
+                    lines: lines(&["5 6"]),
+                    answers: [None, Some(30)],
+                },
+            ]
+        );
+    }
+
+    // A drawing after the input is not another input, and an input split by
+    // blank lines stays one.
+    #[test]
+    fn drawings_and_blank_lines() {
+        let description = "# Part one
+
+For example:
+
+seeds: 1
+
+a map:
+2 3
+
+Drawn out:
+
+1 -> 3
+
+So the lowest location is 3.
+";
+        assert_eq!(
+            examples(description),
+            [Example {
+                lines: lines(&["seeds: 1", "", "a map:", "2 3"]),
+                answers: [Some(3), None],
+            }]
+        );
+    }
+
+    #[test]
+    fn without_answers() {
+        assert_eq!(examples("# Part one\n\nFor example:\n\n1 2\n"), []);
+        assert_eq!(
+            known_answers("part_one 3\npart_two unknown\n"),
+            [Some(3), None]
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// What every generator understands. `size` scales the input (lines, grid side,
+// bricks...), `difficulty` turns on the nastier shapes of each day.
+#[derive(Debug, Clone, Copy)]
+pub struct Knobs {
+    pub size: usize,
+    pub difficulty: usize,
+}
+
+// Answers are only filled in when the construction itself fixes them.
+#[derive(Debug, Clone, Default)]
+pub struct Generated {
+    pub lines: Vec<String>,
+    pub part_one: Option<i128>,
+    pub part_two: Option<i128>,
+}
+
+impl Generated {
+    pub fn new(lines: Vec<String>) -> Generated {
+        Generated {
+            lines,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_answers(mut self, part_one: Option<i128>, part_two: Option<i128>) -> Generated {
+        self.part_one = part_one;
+        self.part_two = part_two;
+        self
+    }
+
+    pub fn answer_lines(&self) -> Vec<String> {
+        [("part_one", self.part_one), ("part_two", self.part_two)]
+            .iter()
+            .map(|(part, answer)| match answer {
+                Some(answer) => format!("{part} {answer}"),
+                None => format!("{part} unknown"),
+            })
+            .collect()
+    }
+
+    // input.txt, plus answer.txt when asked for.
+    pub fn write(&self, directory: &str, answers: bool) -> io::Result<()> {
+        fs::create_dir_all(directory)?;
+        let directory = Path::new(directory);
+        fs::write(directory.join("input.txt"), self.lines.join("\n") + "\n")?;
+        if answers {
+            fs::write(
+                directory.join("answer.txt"),
+                self.answer_lines().join("\n") + "\n",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn random_word<R: Rng>(rng: &mut R, alphabet: &[u8], len: usize) -> String {
+    (0..len)
+        .map(|_| alphabet[rng.gen_range(0..alphabet.len())] as char)
+        .collect()
+}
+
+// Closed rectilinear loop shaped like a skyline: a flat bottom row, then
+// `blocks` columns of random heights. Corners only, clockwise from the
+// bottom left, as (row, column).
+pub fn skyline<R: Rng>(rng: &mut R, blocks: usize, height: i64, width: i64) -> Vec<(i64, i64)> {
+    let bottom = height - 1;
+    // Too flat for two different heights, one block then.
+    let blocks = match bottom {
+        ..=2 => 1,
+        _ => (blocks.max(1) as i64).min(width - 1),
+    };
+
+    let mut cuts = vec![0, width - 1];
+    while (cuts.len() as i64) < blocks + 1 {
+        let cut = rng.gen_range(1..width - 1);
+        if !cuts.contains(&cut) {
+            cuts.push(cut);
+        }
+    }
+    cuts.sort();
+
+    let mut corners = vec![(bottom, 0)];
+    let mut previous = None;
+    for pair in cuts.windows(2) {
+        // Heights differ from block to block, so each cut is a real corner.
+        let mut top = rng.gen_range(0..=bottom - 2);
+        while Some(top) == previous {
+            top = rng.gen_range(0..=bottom - 2);
+        }
+        corners.push((top, pair[0]));
+        corners.push((top, pair[1]));
+        previous = Some(top);
+    }
+    corners.push((bottom, width - 1));
+    corners
+}
+
+// Twice the area, and the number of lattice points, of a corner loop.
+pub fn shoelace(corners: &[(i64, i64)]) -> (i64, i64) {
+    let mut twice_area = 0;
+    let mut boundary = 0;
+    for (k, (i0, j0)) in corners.iter().enumerate() {
+        let (i1, j1) = corners[(k + 1) % corners.len()];
+        twice_area += i0 * j1 - i1 * j0;
+        boundary += (i1 - i0).abs() + (j1 - j0).abs();
+    }
+    (twice_area.abs(), boundary)
+}
+
+pub fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+// None when it does not fit.
+pub fn binomial(n: u128, k: u128) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    (0..k.min(n - k)).try_fold(1_u128, |acc, i| Some(acc.checked_mul(n - i)? / (i + 1)))
+}
+
+pub fn seeded(seed: u64) -> StdRng {
+    rand::SeedableRng::seed_from_u64(seed)
+}
@@ -3,7 +3,7 @@ use std::io::{self, BufRead};
 use std::path::Path;
 use std::str::FromStr;
 
-pub fn line_to_ints<T: FromStr>(string: &String, ch: char) -> Vec<T> {
+pub fn line_to_ints<T: FromStr>(string: &str, ch: char) -> Vec<T> {
     string
         .split(ch)
         .filter_map(|number| number.parse::<T>().ok())
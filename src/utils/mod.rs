@@ -1 +1,10 @@
+pub mod compat;
+pub mod examples;
+pub mod generate;
 pub mod input_process;
+pub mod polynomial;
+pub mod property;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod tui;
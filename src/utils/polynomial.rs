@@ -0,0 +1,146 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+
+// The sequence, then its differences, then theirs, down to a single value.
+// Day9's old difference vectors.
+pub fn difference_rows(values: &[i128]) -> Vec<Vec<BigInt>> {
+    let mut rows = vec![];
+    let mut current = values
+        .iter()
+        .map(|value| BigInt::from(*value))
+        .collect::<Vec<_>>();
+    while !current.is_empty() {
+        let next = current.windows(2).map(|pair| &pair[1] - &pair[0]).collect();
+        rows.push(current);
+        current = next;
+    }
+    rows
+}
+
+// Newton form: p(x) = sum of differences[k] * C(x, k), where differences[k]
+// is the k-th forward difference of the sequence at index 0.
+// On integer sequences every term stays an integer. Big integers: the
+// differences of an i128 sequence, or C(10^9, 5), already go past i128.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    differences: Vec<BigInt>,
+}
+
+impl Polynomial {
+    // Minimal degree polynomial through (0, values[0]), (1, values[1]), ...
+    pub fn fit(values: &[i128]) -> Polynomial {
+        let differences = difference_rows(values)
+            .into_iter()
+            .map(|row| row[0].clone())
+            .collect();
+        Polynomial::trimmed(differences)
+    }
+
+    // Straight from the forward differences at index 0.
+    pub fn from_differences(differences: Vec<i128>) -> Polynomial {
+        Polynomial::trimmed(differences.into_iter().map(BigInt::from).collect())
+    }
+
+    fn trimmed(mut differences: Vec<BigInt>) -> Polynomial {
+        while differences.len() > 1 && differences.last().is_some_and(|last| last.is_zero()) {
+            differences.pop();
+        }
+        Polynomial { differences }
+    }
+
+    // Forward differences at index 0, differences[k] being the k-th.
+    pub fn differences(&self) -> &[BigInt] {
+        &self.differences
+    }
+
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+
+    // Any index, negative ones included.
+    pub fn evaluate(&self, x: i128) -> BigInt {
+        let mut binomial = BigInt::from(1);
+        let mut value = BigInt::zero();
+        for (k, difference) in self.differences.iter().enumerate() {
+            let k = k as i128;
+            if k > 0 {
+                // C(x, k) = C(x, k - 1) * (x - k + 1) / k, always exact.
+                binomial = binomial * (x - k + 1) / k;
+            }
+            value += difference * &binomial;
+        }
+        value
+    }
+
+    // Monomial coefficients, constant term first.
+    pub fn coefficients(&self) -> Vec<BigRational> {
+        let mut coefficients = vec![BigRational::zero(); self.differences.len()];
+        // x (x - 1) ... (x - k + 1), constant term first, and k!.
+        let mut falling: Vec<BigInt> = vec![BigInt::from(1)];
+        let mut factorial = BigInt::from(1);
+
+        for (k, difference) in self.differences.iter().enumerate() {
+            if k > 0 {
+                let shift = BigInt::from(k - 1);
+                let mut next = vec![BigInt::zero(); falling.len() + 1];
+                for (j, coefficient) in falling.iter().enumerate() {
+                    next[j + 1] += coefficient;
+                    next[j] -= &shift * coefficient;
+                }
+                falling = next;
+                factorial *= k;
+            }
+
+            let scale = BigRational::new(difference.clone(), factorial.clone());
+            for (j, coefficient) in falling.iter().enumerate() {
+                coefficients[j] += &scale * coefficient;
+            }
+        }
+
+        coefficients
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn difference_table() {
+        let rows = difference_rows(&[1, 3, 6, 10]);
+        let expected: Vec<Vec<BigInt>> = vec![
+            vec![1.into(), 3.into(), 6.into(), 10.into()],
+            vec![2.into(), 3.into(), 4.into()],
+            vec![1.into(), 1.into()],
+            vec![0.into()],
+        ];
+        assert_eq!(rows, expected);
+    }
+
+    // Squares, then outside of what was fitted on both sides.
+    #[test]
+    fn fit_and_evaluate() {
+        let squares = Polynomial::fit(&[0, 1, 4, 9, 16]);
+        assert_eq!(squares.degree(), 2);
+        assert_eq!(squares.evaluate(10), BigInt::from(100));
+        assert_eq!(squares.evaluate(-3), BigInt::from(9));
+        assert_eq!(Polynomial::fit(&[5, 5, 5]).degree(), 0);
+    }
+
+    #[test]
+    fn coefficients() {
+        let polynomial = Polynomial::fit(&[1, 6, 15, 28]);
+        let expected = [1, 3, 2].map(|coefficient| BigRational::from_integer(coefficient.into()));
+        assert_eq!(polynomial.coefficients(), expected);
+    }
+
+    // C(10^9, 5), about 8.3 * 10^42, is past i128.
+    #[test]
+    fn evaluate_past_i128() {
+        let polynomial = Polynomial::from_differences(vec![0, 0, 0, 0, 0, 1]);
+        let x = BigInt::from(1_000_000_000);
+        let expected = (0..5).fold(BigInt::from(1), |acc, k| acc * (&x - k)) / 120;
+        assert_eq!(polynomial.evaluate(1_000_000_000), expected);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::utils::generate::seeded;
+use crate::utils::report::caught;
+use rand::rngs::StdRng;
+
+// A failing case, as small as shrinking could get it.
+#[derive(Debug, Clone)]
+pub struct Counterexample<T> {
+    pub case: T,
+    pub message: String,
+    // Which generated case failed first.
+    pub index: usize,
+    pub shrinks: usize,
+}
+
+// A panic counts as a failure, with its message.
+fn run_property<T>(property: &impl Fn(&T) -> Result<(), String>, case: &T) -> Result<(), String> {
+    caught(|| property(case)).unwrap_or_else(|message| Err(format!("panicked: {message}")))
+}
+
+// Runs the property on `cases` generated cases. The first failure is shrunk:
+// the first simpler candidate that still fails takes its place, until none
+// does. Candidates must be strictly simpler, or this never ends.
+pub fn check<T: Clone>(
+    cases: usize,
+    seed: u64,
+    generate: impl Fn(&mut StdRng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Result<usize, Counterexample<T>> {
+    let mut rng = seeded(seed);
+    let mut result = Ok(cases);
+    for index in 0..cases {
+        let case = generate(&mut rng);
+        if let Err(message) = run_property(&property, &case) {
+            let mut counterexample = Counterexample {
+                case,
+                message,
+                index,
+                shrinks: 0,
+            };
+            while let Some((case, message)) = shrink(&counterexample.case)
+                .into_iter()
+                .find_map(|case| run_property(&property, &case).err().map(|err| (case, err)))
+            {
+                counterexample.case = case;
+                counterexample.message = message;
+                counterexample.shrinks += 1;
+            }
+            result = Err(counterexample);
+            break;
+        }
+    }
+
+    result
+}
+
+// Every way to drop one item.
+pub fn without_each<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    (0..items.len())
+        .map(|k| [&items[..k], &items[k + 1..]].concat())
+        .collect()
+}
+
+// Every way to change one item into one of its simpler versions.
+pub fn simplify_each<T: Clone>(items: &[T], simpler: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    items
+        .iter()
+        .enumerate()
+        .flat_map(|(k, item)| {
+            simpler(item).into_iter().map(move |item| {
+                let mut items = items.to_vec();
+                items[k] = item;
+                items
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn small_items(items: &[u32]) -> Result<(), String> {
+        match items.iter().find(|item| **item >= 10) {
+            Some(item) => Err(format!("{item} is not small")),
+            None => Ok(()),
+        }
+    }
+
+    fn shrink(items: &[u32]) -> Vec<Vec<u32>> {
+        let mut simpler = without_each(items);
+        simpler.extend(simplify_each(items, |item| match item {
+            0 => vec![],
+            item => vec![item - 1],
+        }));
+        simpler
+    }
+
+    #[test]
+    fn passing() {
+        let generate =
+            |rng: &mut StdRng| -> Vec<u32> { (0..5).map(|_| rng.gen_range(0..10)).collect() };
+        assert!(matches!(
+            check(
+                20,
+                1,
+                generate,
+                |items: &Vec<u32>| shrink(items),
+                |items: &Vec<u32>| small_items(items)
+            ),
+            Ok(20)
+        ));
+    }
+
+    // Down to the one item, at the smallest value still failing.
+    #[test]
+    fn shrinks_to_smallest() {
+        let generate =
+            |rng: &mut StdRng| -> Vec<u32> { (0..5).map(|_| rng.gen_range(0..100)).collect() };
+        let counterexample = check(
+            20,
+            1,
+            generate,
+            |items: &Vec<u32>| shrink(items),
+            |items: &Vec<u32>| small_items(items),
+        )
+        .unwrap_err();
+        assert_eq!(counterexample.case, vec![10]);
+        assert_eq!(counterexample.message, "10 is not small");
+        assert!(counterexample.shrinks > 0);
+    }
+
+    #[test]
+    fn panic_fails() {
+        let counterexample = check(
+            3,
+            1,
+            |_| vec![3],
+            |items: &Vec<u32>| shrink(items),
+            |items: &Vec<u32>| match items.len() {
+                0 => Ok(()),
+                _ => panic!("boom"),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(counterexample.index, 0);
+        assert_eq!(counterexample.case, vec![0]);
+        assert_eq!(counterexample.message, "panicked: boom");
+    }
+}
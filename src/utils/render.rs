@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub type Rgb = [u8; 3];
+
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    default: Rgb,
+}
+
+impl Palette {
+    pub fn new(colors: &[(char, Rgb)], default: Rgb) -> Palette {
+        Palette {
+            colors: colors.iter().copied().collect(),
+            default,
+        }
+    }
+
+    pub fn get(&self, ch: char) -> Rgb {
+        *self.colors.get(&ch).unwrap_or(&self.default)
+    }
+
+    // A day's palette with some colors replaced, from the command line.
+    pub fn with(mut self, colors: &[Color]) -> Palette {
+        for color in colors {
+            match color.ch {
+                Some(ch) => {
+                    self.colors.insert(ch, color.rgb);
+                }
+                None => self.default = color.rgb,
+            }
+        }
+        self
+    }
+}
+
+// One palette entry, `C=RRGGBB` for the cells drawn as C, or
+// `default=RRGGBB` for all the others.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub ch: Option<char>,
+    pub rgb: Rgb,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Color, String> {
+        let (key, hex) = text
+            .rsplit_once('=')
+            .ok_or(format!("{text}: not C=RRGGBB"))?;
+        let ch = match key {
+            "default" => None,
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(ch), None) => Some(ch),
+                    _ => return Err(format!("{key}: not one character")),
+                }
+            }
+        };
+        let value = match hex.len() {
+            6 => u32::from_str_radix(hex, 16).ok(),
+            _ => None,
+        }
+        .ok_or(format!("{hex}: not RRGGBB"))?;
+        let [_, r, g, b] = value.to_be_bytes();
+        Ok(Color { ch, rgb: [r, g, b] })
+    }
+}
+
+// Binary PPM (P6), each grid cell becomes a scale x scale square.
+pub fn to_ppm(grid: &[String], palette: &Palette, scale: usize) -> Vec<u8> {
+    let height = grid.len();
+    let width = grid
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut ppm = format!("P6\n{} {}\n255\n", width * scale, height * scale).into_bytes();
+    for line in grid {
+        let mut row: Vec<u8> = Vec::with_capacity(width * scale * 3);
+        let chars = line.chars().chain(std::iter::repeat(' ')).take(width);
+        for ch in chars {
+            let rgb = palette.get(ch);
+            for _ in 0..scale {
+                row.extend_from_slice(&rgb);
+            }
+        }
+        for _ in 0..scale {
+            ppm.extend_from_slice(&row);
+        }
+    }
+    ppm
+}
+
+// Numbered frames in a directory, one per recorded step, or kept in memory
+// when there is no directory. Only one step out of `every` is written, plus
+// the one given to `finish`.
+pub struct Frames {
+    directory: Option<PathBuf>,
+    snapshots: Vec<Vec<String>>,
+    palette: Palette,
+    scale: usize,
+    every: usize,
+    steps: usize,
+    written: usize,
+}
+
+impl Frames {
+    pub fn new(
+        directory: &str,
+        palette: Palette,
+        scale: usize,
+        every: usize,
+    ) -> io::Result<Frames> {
+        fs::create_dir_all(directory)?;
+        Ok(Frames {
+            directory: Some(PathBuf::from(directory)),
+            snapshots: vec![],
+            palette,
+            scale: scale.max(1),
+            every: every.max(1),
+            steps: 0,
+            written: 0,
+        })
+    }
+
+    pub fn in_memory(palette: Palette, every: usize) -> Frames {
+        Frames {
+            directory: None,
+            snapshots: vec![],
+            palette,
+            scale: 1,
+            every: every.max(1),
+            steps: 0,
+            written: 0,
+        }
+    }
+
+    pub fn snapshots(&self) -> &[Vec<String>] {
+        &self.snapshots
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    pub fn record(&mut self, grid: &[String]) -> io::Result<()> {
+        self.record_with(|| grid.to_vec())
+    }
+
+    // Same as record, the grid is only built for steps that get written.
+    pub fn record_with(&mut self, grid: impl FnOnce() -> Vec<String>) -> io::Result<()> {
+        let step = self.steps;
+        self.steps += 1;
+        if step.is_multiple_of(self.every) {
+            self.write(&grid())?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(&mut self, grid: &[String]) -> io::Result<()> {
+        self.write(grid)
+    }
+
+    fn write(&mut self, grid: &[String]) -> io::Result<()> {
+        match &self.directory {
+            Some(directory) => {
+                let path = directory.join(format!("frame_{:06}.ppm", self.written));
+                File::create(path)?.write_all(&to_ppm(grid, &self.palette, self.scale))?;
+            }
+            None => self.snapshots.push(grid.to_vec()),
+        }
+        self.written += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+use itertools::Itertools;
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    // Nothing to compare with.
+    Solved,
+    Right,
+    Wrong { expected: String },
+    Failed(String),
+    // Failed, but there was no answer to compare with either.
+    Skipped(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Right => write!(f, "right"),
+            Status::Wrong { expected } => write!(f, "wrong, expected {expected}"),
+            Status::Failed(message) => write!(f, "failed: {message}"),
+            Status::Skipped(message) => write!(f, "skipped: {message}"),
+        }
+    }
+}
+
+// One answer of one part. Parts are "one" and "two", with a suffix when a day
+// answers the same part in several ways (day1's locales).
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: String,
+    pub answer: Option<String>,
+    pub input: String,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+impl Outcome {
+    // Against answer.txt lines, as written by the generators and fixtures.
+    pub fn check(&mut self, answer_lines: &[String]) {
+        let key = format!("part_{}", self.part);
+        let expected = answer_lines
+            .iter()
+            .filter_map(|line| line.split_once(' '))
+            .find(|(part, answer)| *part == key && *answer != "unknown");
+        match (expected, &self.answer) {
+            (Some((_, expected)), Some(answer)) if answer == expected => {
+                self.status = Status::Right
+            }
+            (Some((_, expected)), Some(_)) => {
+                self.status = Status::Wrong {
+                    expected: expected.to_string(),
+                }
+            }
+            (None, _) => {
+                if let Status::Failed(message) = &self.status {
+                    self.status = Status::Skipped(message.clone())
+                }
+            }
+            _ => (),
+        }
+    }
+
+    // A known answer missed, wrongly or not at all.
+    pub fn is_mismatch(&self) -> bool {
+        matches!(self.status, Status::Wrong { .. } | Status::Failed(_))
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast_ref::<String>() {
+        Some(message) => message.clone(),
+        None => payload
+            .downcast_ref::<&str>()
+            .map_or("unknown panic".to_string(), |message| message.to_string()),
+    }
+}
+
+// Runs `f`, a panic becomes its message. The default hook would print it on
+// top, so it is off meanwhile.
+pub fn caught<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message);
+    panic::set_hook(hook);
+    result
+}
+
+pub struct Reporter {
+    year: u32,
+    day: u32,
+    input: String,
+    outcomes: Vec<Outcome>,
+}
+
+impl Reporter {
+    pub fn new(year: u32, day: u32, input: &str) -> Reporter {
+        Reporter {
+            year,
+            day,
+            input: input.to_string(),
+            outcomes: vec![],
+        }
+    }
+
+    // Times one call answering the given parts. Parts solved together share
+    // its duration, and a panic fails all of them.
+    pub fn solve<const N: usize>(
+        &mut self,
+        parts: [&str; N],
+        solve: impl FnOnce() -> [Result<String, String>; N],
+    ) {
+        let start = Instant::now();
+        let answers = caught(solve);
+        let duration = start.elapsed();
+
+        for (k, part) in parts.iter().enumerate() {
+            let answer = match &answers {
+                Ok(answers) => answers[k].clone(),
+                Err(message) => Err(format!("panicked: {message}")),
+            };
+            let (answer, status) = match answer {
+                Ok(answer) => (Some(answer), Status::Solved),
+                Err(message) => (None, Status::Failed(message)),
+            };
+            self.outcomes.push(Outcome {
+                year: self.year,
+                day: self.day,
+                part: part.to_string(),
+                answer,
+                input: self.input.clone(),
+                duration,
+                status,
+            });
+        }
+    }
+
+    pub fn outcomes(self) -> Vec<Outcome> {
+        self.outcomes
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Tsv,
+}
+
+const COLUMNS: [&str; 7] = ["year", "day", "part", "answer", "status", "millis", "input"];
+
+fn cells(outcome: &Outcome) -> [String; 7] {
+    [
+        outcome.year.to_string(),
+        outcome.day.to_string(),
+        outcome.part.clone(),
+        outcome.answer.clone().unwrap_or_default(),
+        outcome.status.to_string(),
+        format!("{:.3}", outcome.duration.as_secs_f64() * 1000.0),
+        outcome.input.clone(),
+    ]
+}
+
+// No serde_json here, and the strings are simple enough.
+fn json_string(text: &str) -> String {
+    let mut escaped = String::from('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_line(outcome: &Outcome) -> String {
+    let answer = outcome
+        .answer
+        .as_deref()
+        .map_or("null".to_string(), json_string);
+    let (status, detail) = match &outcome.status {
+        Status::Solved => ("solved", None),
+        Status::Right => ("right", None),
+        Status::Wrong { expected } => ("wrong", Some(expected)),
+        Status::Failed(message) => ("failed", Some(message)),
+        Status::Skipped(message) => ("skipped", Some(message)),
+    };
+    let detail = detail.map_or("null".to_string(), |detail| json_string(detail));
+    format!(
+        "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{answer},\"status\":\"{status}\",\"detail\":{detail},\"millis\":{:.3},\"input\":{}}}",
+        outcome.year,
+        outcome.day,
+        json_string(&outcome.part),
+        outcome.duration.as_secs_f64() * 1000.0,
+        json_string(&outcome.input)
+    )
+}
+
+// Tabs and newlines would break the columns.
+fn tsv_cell(cell: &str) -> String {
+    cell.replace(['\t', '\n'], " ")
+}
+
+pub fn render(outcomes: &[Outcome], format: Format) -> Vec<String> {
+    match format {
+        Format::Table => {
+            let rows = outcomes.iter().map(cells).collect_vec();
+            let widths = (0..COLUMNS.len())
+                .map(|c| {
+                    rows.iter()
+                        .map(|row| row[c].chars().count())
+                        .chain([COLUMNS[c].len()])
+                        .max()
+                        .unwrap()
+                })
+                .collect_vec();
+            let header = COLUMNS.map(|column| column.to_string());
+            [header]
+                .iter()
+                .chain(rows.iter())
+                .map(|row| {
+                    row.iter()
+                        .zip(&widths)
+                        .map(|(cell, width)| format!("{cell:<width$}"))
+                        .join("  ")
+                        .trim_end()
+                        .to_string()
+                })
+                .collect()
+        }
+        Format::Json => outcomes.iter().map(json_line).collect(),
+        Format::Tsv => [COLUMNS.join("\t")]
+            .into_iter()
+            .chain(
+                outcomes
+                    .iter()
+                    .map(|outcome| cells(outcome).map(|cell| tsv_cell(&cell)).join("\t")),
+            )
+            .collect(),
+    }
+}
+
+pub fn print(outcomes: &[Outcome], format: Format) {
+    for line in render(outcomes, format) {
+        println!("{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(part: &str, answer: Option<&str>, status: Status) -> Outcome {
+        Outcome {
+            year: 2023,
+            day: 7,
+            part: part.to_string(),
+            answer: answer.map(|answer| answer.to_string()),
+            input: "./days/2023/day7/input.txt".to_string(),
+            duration: Duration::from_micros(1500),
+            status,
+        }
+    }
+
+    #[test]
+    fn json() {
+        let outcomes = [
+            outcome("one", Some("6440"), Status::Right),
+            outcome("two", None, Status::Failed("bad \"card\"\tJ".to_string())),
+        ];
+        assert_eq!(
+            render(&outcomes, Format::Json),
+            [
+                r#"{"year":2023,"day":7,"part":"one","answer":"6440","status":"right","detail":null,"millis":1.500,"input":"./days/2023/day7/input.txt"}"#,
+                r#"{"year":2023,"day":7,"part":"two","answer":null,"status":"failed","detail":"bad \"card\"\u0009J","millis":1.500,"input":"./days/2023/day7/input.txt"}"#,
+            ]
+        );
+    }
+
+    // Tabs in a cell would shift the columns after it.
+    #[test]
+    fn tsv() {
+        let outcomes = [
+            outcome(
+                "one",
+                Some("6440"),
+                Status::Wrong {
+                    expected: "6441".to_string(),
+                },
+            ),
+            outcome("two", None, Status::Failed("bad\tcard".to_string())),
+        ];
+        assert_eq!(
+            render(&outcomes, Format::Tsv),
+            [
+                "year\tday\tpart\tanswer\tstatus\tmillis\tinput",
+                "2023\t7\tone\t6440\twrong, expected 6441\t1.500\t./days/2023/day7/input.txt",
+                "2023\t7\ttwo\t\tfailed: bad card\t1.500\t./days/2023/day7/input.txt",
+            ]
+        );
+    }
+
+    #[test]
+    fn check() {
+        let answer_lines = ["part_one 6440".to_string(), "part_two unknown".to_string()];
+        let mut right = outcome("one", Some("6440"), Status::Solved);
+        right.check(&answer_lines);
+        assert_eq!(right.status, Status::Right);
+        let mut skipped = outcome("two", None, Status::Failed("no joker".to_string()));
+        skipped.check(&answer_lines);
+        assert_eq!(skipped.status, Status::Skipped("no joker".to_string()));
+        assert!(!skipped.is_mismatch());
+    }
+}
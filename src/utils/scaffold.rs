@@ -0,0 +1,298 @@
+use crate::years;
+use std::fs;
+use std::path::Path;
+
+// The sources being scaffolded are this crate's own, wherever it runs from.
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+// What a day starts from: both registries filled in, parts failing until
+// they are written, and its test failing until the example is answered.
+const DAY_TEMPLATE: &str = r#"use crate::utils::generate::{Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use rand::rngs::StdRng;
+
+pub fn part_one(lines: &[String]) -> Result<String, String> {
+    let _ = lines;
+    Err("not solved yet".to_string())
+}
+
+pub fn part_two(lines: &[String]) -> Result<String, String> {
+    let _ = lines;
+    Err("not solved yet".to_string())
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new({year}, {day}, input);
+    reporter.solve(["one", "two"], || {
+        let lines = input_to_lines(input);
+        [part_one(&lines), part_two(&lines)]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path({year}, {day})), Format::Table);
+}
+
+// Nothing random yet, an empty input.
+pub fn generate(_rng: &mut StdRng, _knobs: &Knobs) -> Generated {
+    Generated::new(vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::report::Status;
+
+    // Both parts right on the example, once it and its answers are in.
+    #[test]
+    fn example() {
+        let fixture = format!("{}/examples/1", years::data_path({year}, {day}));
+        let answer_lines = input_to_lines(&format!("{fixture}/answer.txt"));
+        for mut outcome in solve(&format!("{fixture}/input.txt")) {
+            outcome.check(&answer_lines);
+            assert_eq!(outcome.status, Status::Right, "part {}", outcome.part);
+        }
+    }
+}
+"#;
+
+// A year's registries by day, the same as y2023's. Its first day is
+// registered right after.
+const YEAR_TEMPLATE: &str = r#"use super::{Animator, Generator, Solver, Visualizer};
+
+pub fn generator(day: u32) -> Option<Generator> {
+    let generator: Generator = match day {
+        _ => return None,
+    };
+    Some(generator)
+}
+
+pub fn solver(day: u32) -> Option<Solver> {
+    let solver: Solver = match day {
+        _ => return None,
+    };
+    Some(solver)
+}
+
+// No day draws yet.
+pub fn animator(_day: u32) -> Option<Animator> {
+    None
+}
+
+pub fn visualizer(_day: u32) -> Option<Visualizer> {
+    None
+}
+"#;
+
+fn rooted(path: &str) -> String {
+    format!("{ROOT}/{}", path.trim_start_matches("./"))
+}
+
+fn years_path() -> String {
+    rooted("src/years/mod.rs")
+}
+
+fn year_path(year: u32) -> String {
+    rooted(&format!("src/years/y{year}"))
+}
+
+fn module_path(year: u32, day: u32) -> String {
+    format!("{}/day{day}.rs", year_path(year))
+}
+
+// `pub mod name;` kept sorted like rustfmt would, and in each registry
+// function its arm for `key`, between the arms around it or else before the
+// catch-all.
+fn register(
+    registry: &str,
+    name: &str,
+    catch_all: &str,
+    key: u32,
+    arms: &[(&str, String)],
+) -> Result<String, String> {
+    let mut lines = registry
+        .lines()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    let at = lines
+        .iter()
+        .position(|line| {
+            line.strip_prefix("pub mod ")
+                .and_then(|line| line.strip_suffix(';'))
+                .is_some_and(|module| module > name)
+        })
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod "))
+                .map(|k| k + 1)
+        })
+        .unwrap_or(0);
+    lines.insert(at, format!("pub mod {name};"));
+    // The first module of a file, apart from what follows.
+    if lines
+        .get(at + 1)
+        .is_some_and(|line| !line.is_empty() && !line.starts_with("pub mod "))
+    {
+        lines.insert(at + 1, String::new());
+    }
+
+    for (function, arm) in arms {
+        let start = lines
+            .iter()
+            .position(|line| line.starts_with(&format!("pub fn {function}(")))
+            .ok_or(format!("no registry function {function}"))?;
+        let end = start
+            + lines[start..]
+                .iter()
+                .take_while(|line| *line != "}")
+                .position(|line| line.trim() == catch_all)
+                .ok_or(format!("no `{catch_all}` in {function}"))?;
+        let before = (start..end)
+            .find(|k| {
+                lines[*k]
+                    .trim()
+                    .split_once(" => ")
+                    .and_then(|(arm_key, _)| arm_key.parse::<u32>().ok())
+                    .is_some_and(|arm_key| arm_key > key)
+            })
+            .unwrap_or(end);
+        let indent = &lines[end][..lines[end].len() - lines[end].trim_start().len()];
+        lines.insert(before, format!("{indent}{arm}"));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn declares(registry: &str, name: &str) -> bool {
+    registry
+        .lines()
+        .any(|line| line == format!("pub mod {name};"))
+}
+
+// Each file with what it held before, None for a new one. On a failure the
+// ones written are put back.
+fn write_all(files: &[(String, String, Option<String>)]) -> Result<(), String> {
+    for (k, (path, contents, _)) in files.iter().enumerate() {
+        if let Err(err) = fs::write(path, contents) {
+            for (path, _, previous) in files[..k].iter().rev() {
+                let _ = match previous {
+                    Some(previous) => fs::write(path, previous),
+                    None => fs::remove_file(path),
+                };
+            }
+            return Err(format!("{path}: {err}"));
+        }
+    }
+    Ok(())
+}
+
+// Paths written, under the crate's directory. A year seen for the first time
+// gets its module and registries too. Every target is checked before
+// anything is written, nothing is when any of it is already there.
+pub fn new_day(year: u32, day: u32) -> Result<Vec<String>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("no day {day}, days go from 1 to 25"));
+    }
+    let read = |path: &str| fs::read_to_string(path).map_err(|err| format!("{path}: {err}"));
+    let years_registry = read(&years_path())?;
+    let new_year = !declares(&years_registry, &format!("y{year}"));
+    let registry = format!("{}/mod.rs", year_path(year));
+    let day_registry = match new_year {
+        true => None,
+        false => Some(read(&registry)?),
+    };
+    let existing = day_registry.as_deref().unwrap_or(YEAR_TEMPLATE);
+    if declares(existing, &format!("day{day}")) {
+        return Err(format!("day{day} is already registered in {registry}"));
+    }
+    let data = rooted(&years::data_path(year, day));
+    let mut paths = vec![module_path(year, day), data.clone()];
+    if new_year {
+        paths.push(year_path(year));
+    }
+    for path in paths {
+        if Path::new(&path).exists() {
+            return Err(format!("{path} already exists"));
+        }
+    }
+
+    // The directories made here, removed again on a failure: the data one,
+    // or its year's, and the year's module.
+    let fixture = format!("{data}/examples/1");
+    let year_data = rooted(&format!("days/{year}"));
+    let mut made = vec![match Path::new(&year_data).exists() {
+        true => data.clone(),
+        false => year_data,
+    }];
+    if new_year {
+        made.push(year_path(year));
+    }
+    let registered = register(
+        existing,
+        &format!("day{day}"),
+        "_ => return None,",
+        day,
+        &[
+            ("generator", format!("{day} => day{day}::generate,")),
+            ("solver", format!("{day} => day{day}::solve,")),
+        ],
+    )
+    .map_err(|err| format!("{registry}: {err}"))?;
+    let mut files = vec![
+        (
+            module_path(year, day),
+            DAY_TEMPLATE
+                .replace("{year}", &year.to_string())
+                .replace("{day}", &day.to_string()),
+            None,
+        ),
+        (format!("{data}/example.txt"), String::new(), None),
+        (format!("{data}/description.txt"), String::new(), None),
+        // The day's test fails on it until both parts are solved, and
+        // `run --examples` skips them; the example and its answers then go
+        // in by hand.
+        (format!("{fixture}/input.txt"), String::new(), None),
+        (
+            format!("{fixture}/answer.txt"),
+            "part_one unknown\npart_two unknown\n".to_string(),
+            None,
+        ),
+        (registry, registered, day_registry),
+    ];
+    if new_year {
+        files.push((
+            years_path(),
+            register(
+                &years_registry,
+                &format!("y{year}"),
+                "_ => None,",
+                year,
+                &[
+                    ("generator", format!("{year} => y{year}::generator(day),")),
+                    ("solver", format!("{year} => y{year}::solver(day),")),
+                    ("animator", format!("{year} => y{year}::animator(day),")),
+                    ("visualizer", format!("{year} => y{year}::visualizer(day),")),
+                ],
+            )
+            .map_err(|err| format!("{}: {err}", years_path()))?,
+            Some(years_registry),
+        ));
+    }
+
+    let written = [fixture, year_path(year)]
+        .iter()
+        .try_for_each(|directory| {
+            fs::create_dir_all(directory).map_err(|err| format!("{directory}: {err}"))
+        })
+        .and_then(|_| write_all(&files));
+    if written.is_err() {
+        for directory in made {
+            let _ = fs::remove_dir_all(directory);
+        }
+    }
+    written?;
+    Ok(files.into_iter().map(|(path, _, _)| path).collect())
+}
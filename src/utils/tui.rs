@@ -0,0 +1,124 @@
+use crate::utils::render::Frames;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::terminal::{
+    self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+    LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+use std::io::{self, Write};
+use std::time::Duration;
+
+const KEYS: &str = "n/→ next  p/← previous  e run to end  q quit";
+
+fn draw(
+    out: &mut impl Write,
+    title: &str,
+    frames: &Frames,
+    index: usize,
+    answer: &dyn Fn(&[String]) -> String,
+) -> io::Result<()> {
+    let snapshots = frames.snapshots();
+    let grid = &snapshots[index];
+    let (columns, rows) = terminal::size()?;
+
+    queue!(out, Clear(ClearType::All), MoveTo(0, 0), ResetColor)?;
+    queue!(
+        out,
+        Print(format!("{title}  step {}/{}", index + 1, snapshots.len())),
+        MoveTo(0, 1),
+        Print(format!("answer: {}", answer(grid))),
+        MoveTo(0, 2),
+        Print(KEYS),
+    )?;
+
+    // Whatever fits, from the top left corner.
+    for (i, line) in grid
+        .iter()
+        .take(rows.saturating_sub(4) as usize)
+        .enumerate()
+    {
+        queue!(out, MoveTo(0, i as u16 + 4))?;
+        for ch in line.chars().take(columns as usize) {
+            let [r, g, b] = frames.palette().get(ch);
+            queue!(out, SetForegroundColor(Color::Rgb { r, g, b }), Print(ch))?;
+        }
+    }
+    queue!(out, ResetColor)?;
+    out.flush()
+}
+
+// Blocks until a key is pressed, or returns None after the timeout.
+fn read_key(timeout: Option<Duration>) -> io::Result<Option<KeyCode>> {
+    if let Some(timeout) = timeout {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+    }
+    match event::read()? {
+        Event::Key(KeyEvent {
+            code,
+            kind: KeyEventKind::Press,
+            ..
+        }) => Ok(Some(code)),
+        _ => Ok(None),
+    }
+}
+
+fn browse_loop(
+    out: &mut impl Write,
+    title: &str,
+    frames: &Frames,
+    answer: &dyn Fn(&[String]) -> String,
+) -> io::Result<()> {
+    let last = frames.snapshots().len() - 1;
+    let mut index = 0;
+    let mut running = false;
+
+    loop {
+        draw(out, title, frames, index, answer)?;
+
+        let timeout = running.then_some(Duration::from_millis(40));
+        match read_key(timeout)? {
+            None if running => {
+                index = (index + 1).min(last);
+                running = index < last;
+            }
+            Some(KeyCode::Char('q')) | Some(KeyCode::Esc) => return Ok(()),
+            Some(KeyCode::Char('n')) | Some(KeyCode::Right) | Some(KeyCode::Char(' ')) => {
+                running = false;
+                index = (index + 1).min(last);
+            }
+            Some(KeyCode::Char('p')) | Some(KeyCode::Left) => {
+                running = false;
+                index = index.saturating_sub(1);
+            }
+            Some(KeyCode::Char('e')) | Some(KeyCode::End) => running = index < last,
+            _ => {}
+        }
+    }
+}
+
+// Steps through recorded snapshots in a raw, alternate screen terminal.
+// The answer closure gets the snapshot on display.
+pub fn browse(
+    title: &str,
+    frames: &Frames,
+    answer: &dyn Fn(&[String]) -> String,
+) -> io::Result<()> {
+    if frames.snapshots().is_empty() {
+        println!("{title}: nothing recorded.");
+        return Ok(());
+    }
+
+    let mut out = io::stdout();
+    enable_raw_mode()?;
+    execute!(out, EnterAlternateScreen, Hide)?;
+
+    let result = browse_loop(&mut out, title, frames, answer);
+
+    execute!(out, Show, LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    result
+}
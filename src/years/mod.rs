@@ -0,0 +1,67 @@
+pub mod y2023;
+
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::render::Color;
+use crate::utils::report::Outcome;
+use rand::rngs::StdRng;
+
+// What the commands use when no --year is given.
+pub const DEFAULT_YEAR: u32 = 2023;
+
+pub type Generator = fn(&mut StdRng, &Knobs) -> Generated;
+
+// Input path in, one outcome per part answered.
+pub type Solver = fn(&str) -> Vec<Outcome>;
+
+// PPM frames of the solving: input path, output directory, palette overrides
+// and a scale, the day's own when None.
+pub type Animator = fn(&str, &str, &[Color], Option<usize>);
+
+// The solving browsed step by step in the terminal, from an input path.
+pub type Visualizer = fn(&str);
+
+// Each year keeps its own registry by day; these pick the year.
+pub fn generator(year: u32, day: u32) -> Option<Generator> {
+    match year {
+        2023 => y2023::generator(day),
+        _ => None,
+    }
+}
+
+pub fn solver(year: u32, day: u32) -> Option<Solver> {
+    match year {
+        2023 => y2023::solver(day),
+        _ => None,
+    }
+}
+
+pub fn animator(year: u32, day: u32) -> Option<Animator> {
+    match year {
+        2023 => y2023::animator(day),
+        _ => None,
+    }
+}
+
+pub fn visualizer(year: u32, day: u32) -> Option<Visualizer> {
+    match year {
+        2023 => y2023::visualizer(day),
+        _ => None,
+    }
+}
+
+// Days with a solver, in order.
+pub fn days(year: u32) -> Vec<u32> {
+    (1..=25)
+        .filter(|&day| solver(year, day).is_some())
+        .collect()
+}
+
+// Input data of a day, days/YYYY/dayN/.
+pub fn data_path(year: u32, day: u32) -> String {
+    format!("./days/{year}/day{day}")
+}
+
+// The puzzle input in there.
+pub fn input_path(year: u32, day: u32) -> String {
+    format!("{}/input.txt", data_path(year, day))
+}
@@ -0,0 +1,366 @@
+use crate::utils::generate::{random_word, Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{self, BufRead};
+use std::path::Path;
+
+// Consting all that can be
+const WORDS_1: [Word; 9] = [
+    Word {
+        word: "1",
+        number: "1",
+    },
+    Word {
+        word: "2",
+        number: "2",
+    },
+    Word {
+        word: "3",
+        number: "3",
+    },
+    Word {
+        word: "4",
+        number: "4",
+    },
+    Word {
+        word: "5",
+        number: "5",
+    },
+    Word {
+        word: "6",
+        number: "6",
+    },
+    Word {
+        word: "7",
+        number: "7",
+    },
+    Word {
+        word: "8",
+        number: "8",
+    },
+    Word {
+        word: "9",
+        number: "9",
+    },
+];
+
+const WORDS_2: [Word; 9] = [
+    Word {
+        word: "one",
+        number: "1",
+    },
+    Word {
+        word: "two",
+        number: "2",
+    },
+    Word {
+        word: "three",
+        number: "3",
+    },
+    Word {
+        word: "four",
+        number: "4",
+    },
+    Word {
+        word: "five",
+        number: "5",
+    },
+    Word {
+        word: "six",
+        number: "6",
+    },
+    Word {
+        word: "seven",
+        number: "7",
+    },
+    Word {
+        word: "eight",
+        number: "8",
+    },
+    Word {
+        word: "nine",
+        number: "9",
+    },
+];
+
+#[derive(Debug, Clone)]
+struct Word<'a> {
+    word: &'a str,
+    number: &'a str,
+}
+
+// Aho-Corasick over the vocabulary: one pass per line, overlapping
+// words like "twone" are all reported.
+struct Matcher<'a> {
+    goto: Vec<HashMap<char, usize>>,
+    fail: Vec<usize>,
+    outputs: Vec<Vec<&'a Word<'a>>>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(words: &'a [Word<'a>]) -> Self {
+        let mut goto: Vec<HashMap<char, usize>> = vec![HashMap::new()];
+        let mut outputs: Vec<Vec<&Word>> = vec![vec![]];
+
+        for word in words {
+            let mut node = 0;
+            for ch in word.word.chars() {
+                node = match goto[node].get(&ch) {
+                    Some(next) => *next,
+                    None => {
+                        goto.push(HashMap::new());
+                        outputs.push(vec![]);
+                        let next = goto.len() - 1;
+                        goto[node].insert(ch, next);
+                        next
+                    }
+                };
+            }
+            outputs[node].push(word);
+        }
+
+        // Breadth first, so a fail link always points to a finished node.
+        let mut fail = vec![0; goto.len()];
+        let mut queue: VecDeque<usize> = goto[0].values().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            for (ch, next) in goto[node].clone() {
+                let mut link = fail[node];
+                while link != 0 && !goto[link].contains_key(&ch) {
+                    link = fail[link];
+                }
+                fail[next] = match goto[link].get(&ch) {
+                    Some(target) if *target != next => *target,
+                    _ => 0,
+                };
+                let inherited = outputs[fail[next]].clone();
+                outputs[next].extend(inherited);
+                queue.push_back(next);
+            }
+        }
+
+        Matcher {
+            goto,
+            fail,
+            outputs,
+        }
+    }
+
+    // (start, word) for every occurrence, ordered by end position.
+    fn find_all(&self, line: &str) -> Vec<(usize, &'a Word<'a>)> {
+        let mut findings = vec![];
+        let mut node = 0;
+        for (end, ch) in line.char_indices() {
+            while node != 0 && !self.goto[node].contains_key(&ch) {
+                node = self.fail[node];
+            }
+            node = *self.goto[node].get(&ch).unwrap_or(&0);
+            for word in self.outputs[node].iter() {
+                findings.push((end + ch.len_utf8() - word.word.len(), *word));
+            }
+        }
+        findings
+    }
+}
+
+fn get_first_match(line: &str, matcher: &Matcher) -> Option<u32> {
+    let findings = matcher.find_all(line);
+    let (_, first) = findings.iter().min_by_key(|(start, _)| *start)?;
+    let (_, last) = findings.iter().max_by_key(|(start, _)| *start)?;
+
+    let together: u32 = format!("{}{}", first.number, last.number)
+        .parse()
+        .expect("Err: Could not parse number");
+
+    Some(together)
+}
+
+// One "word number" pair per line, e.g. "trois 3".
+fn parse_vocabulary(lines: &[String]) -> Vec<Word<'_>> {
+    lines
+        .iter()
+        .filter_map(|line| line.split_once(' '))
+        .map(|(word, number)| Word {
+            word: word.trim(),
+            number: number.trim(),
+        })
+        .collect()
+}
+
+// Number words of one locale, read at runtime. words_fr.txt is locale "fr".
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    locale: String,
+    lines: Vec<String>,
+}
+
+impl Vocabulary {
+    pub fn from_file(path: &str) -> io::Result<Vocabulary> {
+        let stem = Path::new(path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or(path);
+        let locale = stem.strip_prefix("words_").unwrap_or(stem).to_string();
+        let lines = fs::read_to_string(path)?
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+        Ok(Vocabulary { locale, lines })
+    }
+
+    // The digits, then the words of the file.
+    fn words(&self) -> Vec<Word<'_>> {
+        let mut words: Vec<Word> = Vec::from(WORDS_1);
+        words.extend(parse_vocabulary(&self.lines));
+        words
+    }
+}
+
+// Every words_*.txt of a directory, by locale.
+fn vocabularies(directory: &str) -> io::Result<Vec<Vocabulary>> {
+    let mut paths = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok()?.path().to_str().map(String::from))
+        .filter(|path| {
+            Path::new(path)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("words_") && name.ends_with(".txt"))
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+        .iter()
+        .map(|path| Vocabulary::from_file(path))
+        .collect()
+}
+
+// The sum, and the line numbers without any digit.
+fn day1(input: &str, words: &[Word]) -> (u32, Vec<usize>) {
+    let fpath = Path::new(input);
+    let file = File::open(fpath).unwrap();
+    let lines = io::BufReader::new(file).lines();
+    let matcher = Matcher::new(words);
+
+    lines.enumerate().fold(
+        (0, vec![]),
+        |(acc, mut missing), (i, line)| match get_first_match(
+            &line.expect("Err: could not use line"),
+            &matcher,
+        ) {
+            Some(number) => (acc + number, missing),
+            None => {
+                missing.push(i + 1);
+                (acc, missing)
+            }
+        },
+    )
+}
+
+// A line without a digit makes the whole sum wrong.
+fn answer((result, missing): (u32, Vec<usize>)) -> Result<String, String> {
+    match missing.is_empty() {
+        true => Ok(result.to_string()),
+        false => Err(format!(
+            "no digit on lines {}",
+            missing
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+// Part two again in each locale of days/2023/day1.
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let vocabularies = vocabularies(&years::data_path(2023, 1)).unwrap_or_default();
+    solve_with(input, &vocabularies)
+}
+
+pub fn solve_with(input: &str, vocabularies: &[Vocabulary]) -> Vec<Outcome> {
+    let words_1: Vec<Word> = Vec::from(WORDS_1);
+    let mut words_2: Vec<Word> = Vec::from(WORDS_1);
+    words_2.extend_from_slice(&WORDS_2);
+
+    let mut reporter = Reporter::new(2023, 1, input);
+    reporter.solve(["one"], || [answer(day1(input, &words_1))]);
+    reporter.solve(["two"], || [answer(day1(input, &words_2))]);
+    for vocabulary in vocabularies {
+        reporter.solve([&format!("two {}", vocabulary.locale)], || {
+            [answer(day1(input, &vocabulary.words()))]
+        });
+    }
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 1)), Format::Table);
+}
+
+// size lines. difficulty 1 spells some digits out, 2 also glues words that
+// share a letter, as in "oneight". Answers use the English words only.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    const GLUED: [(&str, u32, u32); 8] = [
+        ("oneight", 1, 8),
+        ("twone", 2, 1),
+        ("threeight", 3, 8),
+        ("fiveight", 5, 8),
+        ("sevenine", 7, 9),
+        ("eightwo", 8, 2),
+        ("eighthree", 8, 3),
+        ("nineight", 9, 8),
+    ];
+    // None of these letters is in a digit word.
+    const FILLER: &[u8] = b"abcdjklmpqyz";
+
+    let mut lines = vec![];
+    let (mut part_one, mut part_two) = (0, 0);
+    for _ in 0..knobs.size.max(1) {
+        let tokens = rng.gen_range(1..=6);
+        // At least one real digit per line.
+        let forced = rng.gen_range(0..tokens);
+        let mut line = String::new();
+        let mut digits = vec![];
+        let mut values = vec![];
+        for token in 0..tokens {
+            let filler = rng.gen_range(usize::from(token > 0)..4);
+            line += &random_word(rng, FILLER, filler);
+            let kind = match token == forced {
+                true => 0,
+                false => rng.gen_range(0..=knobs.difficulty.min(2)),
+            };
+            match kind {
+                0 => {
+                    let digit = rng.gen_range(1..=9);
+                    line += &digit.to_string();
+                    digits.push(digit);
+                    values.push((digit, digit));
+                }
+                1 => {
+                    let digit = rng.gen_range(1..=9);
+                    line += WORDS[digit as usize - 1];
+                    values.push((digit, digit));
+                }
+                _ => {
+                    let (word, first, last) = GLUED[rng.gen_range(0..GLUED.len())];
+                    line += word;
+                    values.push((first, last));
+                }
+            }
+        }
+        let filler = rng.gen_range(0..4);
+        line += &random_word(rng, FILLER, filler);
+
+        part_one += digits[0] * 10 + digits[digits.len() - 1];
+        part_two += values[0].0 * 10 + values[values.len() - 1].1;
+        lines.push(line);
+    }
+
+    Generated::new(lines).with_answers(Some(part_one as i128), Some(part_two as i128))
+}
@@ -0,0 +1,362 @@
+use crate::utils::generate::{shoelace, skyline, Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::render::{Color, Frames, Palette};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub fn get_directions() -> Vec<Direction> {
+        vec![
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Place {
+    pub i: i32,
+    pub j: i32,
+    pub connections: Vec<Direction>,
+    pub value: Option<i32>,
+}
+
+pub struct Map {
+    pub places: Vec<Vec<Place>>,
+    pub height: i32,
+    pub width: i32,
+}
+
+impl Map {
+    pub fn new(places: Vec<Vec<Place>>) -> Map {
+        let height = places.len() as i32;
+        let width = places[0].len() as i32;
+        Map {
+            places,
+            height,
+            width,
+        }
+    }
+
+    pub fn get_place_at_direction(&self, place: &Place, direction: &Direction) -> Option<Place> {
+        let Place { mut i, mut j, .. } = place;
+        match direction {
+            Direction::North => i -= 1,
+            Direction::South => i += 1,
+            Direction::East => j += 1,
+            Direction::West => j -= 1,
+        }
+
+        // Check within map
+        if i < 0_i32 || i > (self.height - 1) || j < 0_i32 || j > (self.width - 1) {
+            return None;
+        }
+
+        let new_place = (self.places[i as usize][j as usize]).clone();
+
+        // Check not visited
+        if new_place.value.is_some() {
+            return None;
+        }
+
+        // Check connected
+        if !match direction {
+            Direction::North => {
+                place.connections.contains(&Direction::North)
+                    && new_place.connections.contains(&Direction::South)
+            }
+            Direction::South => {
+                place.connections.contains(&Direction::South)
+                    && new_place.connections.contains(&Direction::North)
+            }
+            Direction::East => {
+                place.connections.contains(&Direction::East)
+                    && new_place.connections.contains(&Direction::West)
+            }
+            Direction::West => {
+                place.connections.contains(&Direction::West)
+                    && new_place.connections.contains(&Direction::East)
+            }
+        } {
+            return None;
+        }
+
+        Some(new_place)
+    }
+
+    pub fn set_place(&mut self, place: Place) {
+        let Place { i, j, .. } = place;
+        self.places[i as usize][j as usize] = place;
+    }
+
+    pub fn get_move_candidates(&self, place: &Place) -> Vec<Place> {
+        Direction::get_directions()
+            .iter()
+            .filter_map(|direction| self.get_place_at_direction(place, direction))
+            .collect()
+    }
+}
+
+pub fn char_to_place(ch: char, i: i32, j: i32) -> Place {
+    match ch {
+        '|' => Place {
+            connections: vec![Direction::North, Direction::South],
+            value: None,
+            i,
+            j,
+        },
+        '-' => Place {
+            connections: vec![Direction::East, Direction::West],
+            value: None,
+            i,
+            j,
+        },
+        'L' => Place {
+            connections: vec![Direction::North, Direction::East],
+            value: None,
+            i,
+            j,
+        },
+        'J' => Place {
+            connections: vec![Direction::North, Direction::West],
+            value: None,
+            i,
+            j,
+        },
+        '7' => Place {
+            connections: vec![Direction::West, Direction::South],
+            value: None,
+            i,
+            j,
+        },
+        'F' => Place {
+            connections: vec![Direction::East, Direction::South],
+            value: None,
+            i,
+            j,
+        },
+        '.' => Place {
+            connections: vec![],
+            value: None,
+            i,
+            j,
+        },
+        'S' => Place {
+            connections: vec![
+                Direction::North,
+                Direction::South,
+                Direction::East,
+                Direction::West,
+            ],
+            value: Some(0),
+            i,
+            j,
+        },
+        _ => {
+            panic!("Err: unmanaged char {ch}")
+        }
+    }
+}
+
+pub fn get_places(lines: &[String]) -> Vec<Vec<Place>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, ch)| char_to_place(ch, i as i32, j as i32))
+                .collect::<Vec<Place>>()
+        })
+        .collect()
+}
+
+// The walked part of the loop, everything else blanked.
+fn loop_lines(lines: &[String], map: &Map) -> Vec<String> {
+    lines
+        .iter()
+        .zip(map.places.iter())
+        .map(|(line, places)| {
+            line.chars()
+                .zip(places.iter())
+                .map(|(ch, place)| if place.value.is_some() { ch } else { ' ' })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn process_lines(lines: &[String], frames: &mut Option<Frames>) -> (i32, i32) {
+    let map_data = get_places(lines);
+    let mut map = Map::new(map_data);
+
+    // Starting place
+    let mut place = map
+        .places
+        .iter()
+        .flat_map(|line| line.iter())
+        .find(|place| place.value == Some(0))
+        .expect("Err: no starting point")
+        .clone();
+
+    // The start too, the area needs the closed loop.
+    let mut path_p2: Vec<Place> = vec![place.clone()];
+
+    while let Some(next_place) = map.get_move_candidates(&place).first_mut() {
+        let next_value = place.value.expect("Err: no next value") + 1;
+        next_place.value = Some(next_value);
+        // The bad place, shoulddo
+        map.set_place(next_place.clone());
+        place = (*next_place).clone();
+        path_p2.push(place.clone());
+
+        if let Some(frames) = frames {
+            frames
+                .record(&loop_lines(lines, &map))
+                .expect("Err: could not write frame");
+        }
+    }
+
+    if let Some(frames) = frames {
+        frames
+            .finish(&loop_lines(lines, &map))
+            .expect("Err: could not write frame");
+    }
+
+    let area: i32 = path_p2
+        .iter()
+        .circular_tuple_windows()
+        .map(|(place0, place1)| (place0.i + place1.i) * (place0.j - place1.j))
+        .sum::<i32>()
+        .abs()
+        / 2;
+
+    let frontier = (path_p2
+        .last()
+        .expect("Err: no last")
+        .value
+        .expect("Err: no value")
+        - 1)
+        / 2;
+
+    (frontier + 1, area - frontier)
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 10, input);
+    reporter.solve(["one", "two"], || {
+        let data = input_to_lines(input);
+        let (frontier, area) = process_lines(&data, &mut None);
+        [Ok(frontier.to_string()), Ok(area.to_string())]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 10)), Format::Table);
+}
+
+fn palette() -> Palette {
+    let pipe = [240, 140, 60];
+    Palette::new(
+        &[
+            ('|', pipe),
+            ('-', pipe),
+            ('L', pipe),
+            ('J', pipe),
+            ('7', pipe),
+            ('F', pipe),
+            ('S', [255, 60, 60]),
+        ],
+        [15, 15, 25],
+    )
+}
+
+// The loop being walked, a frame every 50 pipes.
+pub(crate) fn animate(input: &str, directory: &str, colors: &[Color], scale: Option<usize>) {
+    let data = input_to_lines(input);
+    let palette = palette().with(colors);
+    let frames =
+        Frames::new(directory, palette, scale.unwrap_or(3), 50).expect("Err: no frame directory");
+    process_lines(&data, &mut Some(frames));
+    println!("\n day10 frames written to {directory}.");
+}
+
+fn pipe(from: (i64, i64), at: (i64, i64), to: (i64, i64)) -> char {
+    let side = |(i, j): (i64, i64)| match (i - at.0, j - at.1) {
+        (-1, 0) => 'N',
+        (1, 0) => 'S',
+        (0, 1) => 'E',
+        _ => 'W',
+    };
+    match [side(from), side(to)] {
+        ['N', 'S'] | ['S', 'N'] => '|',
+        ['E', 'W'] | ['W', 'E'] => '-',
+        ['N', 'E'] | ['E', 'N'] => 'L',
+        ['N', 'W'] | ['W', 'N'] => 'J',
+        ['S', 'W'] | ['W', 'S'] => '7',
+        _ => 'F',
+    }
+}
+
+// A skyline loop on a size x 2size grid, with 1 + difficulty blocks. From
+// difficulty 1 on, the tiles off the loop are junk pipes.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let height = knobs.size.max(3) as i64;
+    let width = 2 * height;
+    let corners = skyline(rng, 1 + knobs.difficulty, height, width);
+    let (twice_area, boundary) = shoelace(&corners);
+
+    // Every tile of the loop, in order.
+    let mut tiles = vec![];
+    for (k, (i0, j0)) in corners.iter().enumerate() {
+        let (i1, j1) = corners[(k + 1) % corners.len()];
+        let steps = (i1 - i0).abs() + (j1 - j0).abs();
+        for step in 0..steps {
+            tiles.push((
+                i0 + (i1 - i0).signum() * step,
+                j0 + (j1 - j0).signum() * step,
+            ));
+        }
+    }
+
+    let mut grid = vec![vec!['.'; width as usize]; height as usize];
+    if knobs.difficulty > 0 {
+        for row in grid.iter_mut() {
+            for tile in row.iter_mut() {
+                *tile = b"|-LJ7F.."[rng.gen_range(0..8)] as char;
+            }
+        }
+    }
+    for (k, at) in tiles.iter().enumerate() {
+        let from = tiles[(k + tiles.len() - 1) % tiles.len()];
+        let to = tiles[(k + 1) % tiles.len()];
+        grid[at.0 as usize][at.1 as usize] = pipe(from, *at, to);
+    }
+
+    // S connects every way, so no junk around it.
+    let start = tiles[rng.gen_range(0..tiles.len())];
+    for (di, dj) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+        let (i, j) = (start.0 + di, start.1 + dj);
+        if (0..height).contains(&i) && (0..width).contains(&j) && !tiles.contains(&(i, j)) {
+            grid[i as usize][j as usize] = '.';
+        }
+    }
+    grid[start.0 as usize][start.1 as usize] = 'S';
+
+    let lines = grid.iter().map(|row| row.iter().collect()).collect();
+    let inside = (twice_area - boundary) / 2 + 1;
+    Generated::new(lines).with_answers(Some(boundary as i128 / 2), Some(inside as i128))
+}
@@ -1,18 +1,23 @@
+use crate::utils::generate::{Generated, Knobs};
 use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
 use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
 
 #[derive(Debug, Clone, PartialEq)]
-enum SpaceType {
+pub enum SpaceType {
     Galaxy,
     Void,
 }
 
 #[derive(Debug, Clone)]
-struct Space {
-    i: usize,
-    j: usize,
-    value: usize,
-    id: SpaceType,
+pub struct Space {
+    pub i: usize,
+    pub j: usize,
+    pub value: usize,
+    pub id: SpaceType,
 }
 
 impl Space {
@@ -26,7 +31,7 @@ impl Space {
     }
 }
 
-fn transpose(spaces: Vec<Vec<Space>>) -> Vec<Vec<Space>> {
+pub fn transpose(spaces: Vec<Vec<Space>>) -> Vec<Vec<Space>> {
     let width = spaces.first().expect("Err: no first").len();
     let height = spaces.len();
 
@@ -39,7 +44,8 @@ fn transpose(spaces: Vec<Vec<Space>>) -> Vec<Vec<Space>> {
     cols
 }
 
-fn expand(rows: Vec<Vec<Space>>) -> Vec<Vec<Space>> {
+// Each empty row stands for `factor` of them.
+pub fn expand(rows: Vec<Vec<Space>>, factor: usize) -> Vec<Vec<Space>> {
     rows.into_iter().fold(Vec::new(), |mut acc, row| {
         let galaxies: Vec<&Space> = row
             .iter()
@@ -50,7 +56,7 @@ fn expand(rows: Vec<Vec<Space>>) -> Vec<Vec<Space>> {
             added_row = added_row
                 .iter_mut()
                 .map(|space| {
-                    space.value = 1000000;
+                    space.value = factor;
                     space.clone()
                 })
                 .collect();
@@ -62,7 +68,7 @@ fn expand(rows: Vec<Vec<Space>>) -> Vec<Vec<Space>> {
     })
 }
 
-fn input_to_spaces(input: &Vec<String>) -> Vec<Vec<Space>> {
+pub fn input_to_spaces(input: &[String]) -> Vec<Vec<Space>> {
     let width = input.first().expect("Err: no first").len();
     let height = input.len();
     let mut spaces: Vec<Vec<Space>> = vec![vec![Space::default(); width]; height];
@@ -82,11 +88,11 @@ fn input_to_spaces(input: &Vec<String>) -> Vec<Vec<Space>> {
     spaces
 }
 
-fn get_galaxies(input: &Vec<String>) -> usize {
+pub fn get_galaxies(input: &[String], factor: usize) -> usize {
     let spaces = input_to_spaces(input);
-    let expanded_rows = expand(spaces);
+    let expanded_rows = expand(spaces, factor);
     let cols = transpose(expanded_rows);
-    let expanded_cols = expand(cols);
+    let expanded_cols = expand(cols, factor);
 
     let mut galaxies: Vec<Space> = Vec::new();
     for (i, row) in expanded_cols.iter().enumerate() {
@@ -119,13 +125,52 @@ fn get_galaxies(input: &Vec<String>) -> usize {
         / 2
 }
 
-fn process_lines(lines: &Vec<String>) -> usize {
-    get_galaxies(lines)
+pub fn process_lines(lines: &[String], factor: usize) -> usize {
+    get_galaxies(lines, factor)
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 11, input);
+    reporter.solve(["one", "two"], || {
+        let data = input_to_lines(input);
+        [
+            Ok(process_lines(&data, 2).to_string()),
+            Ok(process_lines(&data, 1000000).to_string()),
+        ]
+    });
+    reporter.outcomes()
 }
 
 pub fn run() {
-    let input = "./days/day11/input.txt";
-    let data = input_to_lines(input);
-    let result = process_lines(&data);
-    println!("\n day11 done with result {result}.");
+    report::print(&solve(&years::input_path(2023, 11)), Format::Table);
+}
+
+// A size x size image, one galaxy in 20 tiles, fewer empty rows and columns
+// as difficulty grows.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let side = knobs.size.max(2);
+    let empty = |rng: &mut StdRng| {
+        (0..side)
+            .map(|_| rng.gen_bool(0.2 / (1 + knobs.difficulty) as f64))
+            .collect_vec()
+    };
+    let (empty_rows, empty_columns) = (empty(rng), empty(rng));
+
+    let mut lines = (0..side)
+        .map(|i| {
+            (0..side)
+                .map(|j| {
+                    let galaxy = !empty_rows[i] && !empty_columns[j] && rng.gen_bool(0.05);
+                    if galaxy {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect_vec();
+    // At least one galaxy.
+    lines[0].replace_range(0..1, "#");
+    Generated::new(lines)
 }
@@ -0,0 +1,345 @@
+use crate::utils::generate::{binomial, Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum EventId {
+    Dot,
+    Hash,
+    Qmark,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Event {
+    id: EventId,
+}
+
+impl Event {
+    // The concrete springs this event can stand for.
+    fn choices(&self) -> &'static [EventId] {
+        match self.id {
+            EventId::Dot => &[EventId::Dot],
+            EventId::Hash => &[EventId::Hash],
+            EventId::Qmark => &[EventId::Dot, EventId::Hash],
+        }
+    }
+}
+
+impl From<char> for Event {
+    fn from(ch: char) -> Self {
+        match ch {
+            '.' => Event { id: EventId::Dot },
+            '#' => Event { id: EventId::Hash },
+            '?' => Event { id: EventId::Qmark },
+            _ => panic!("Err: unmanaged event {ch}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum StateId {
+    Dot,
+    Hash,
+    End,
+}
+
+#[derive(Clone, Debug, Copy)]
+struct State {
+    id: StateId,
+}
+
+impl State {
+    fn new(ch: char) -> State {
+        match ch {
+            '.' => State { id: StateId::Dot },
+            '#' => State { id: StateId::Hash },
+            'e' => State { id: StateId::End },
+            _ => panic!("Err: unmanaged state {ch}"),
+        }
+    }
+}
+
+// Once the spring is known, the automaton is deterministic.
+fn next_state(states: &[State], k: usize, id: EventId) -> Option<usize> {
+    let next = states.get(k + 1)?;
+    match (states[k].id, id) {
+        (StateId::Dot, EventId::Dot) => Some(k),
+        (_, EventId::Hash) if next.id == StateId::Hash => Some(k + 1),
+        (StateId::Hash, EventId::Dot) if next.id == StateId::Dot => Some(k + 1),
+        _ => None,
+    }
+}
+
+// Done on the last hash of the last group or the dot after it.
+fn is_accepting(states: &[State], k: usize) -> bool {
+    k + 3 >= states.len() && states[k].id != StateId::End
+}
+
+// ways[p][k]: how many ways to finish events[p..] when standing on state k.
+fn completions(events: &[Event], states: &[State]) -> Vec<Vec<u128>> {
+    let mut ways = vec![vec![0_u128; states.len()]; events.len() + 1];
+    for (k, way) in ways[events.len()].iter_mut().enumerate() {
+        if is_accepting(states, k) {
+            *way = 1;
+        }
+    }
+
+    for p in (0..events.len()).rev() {
+        for k in 0..states.len() {
+            ways[p][k] = events[p]
+                .choices()
+                .iter()
+                .filter_map(|id| next_state(states, k, *id))
+                .map(|next| ways[p + 1][next])
+                .sum();
+        }
+    }
+
+    ways
+}
+
+fn event_char(id: EventId) -> char {
+    match id {
+        EventId::Dot => '.',
+        EventId::Hash => '#',
+        EventId::Qmark => '?',
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Unfolding {
+    pub(crate) factor: usize,
+    pub(crate) separator: char,
+}
+
+impl Unfolding {
+    pub fn new(factor: usize, separator: char) -> Unfolding {
+        Unfolding { factor, separator }
+    }
+}
+
+pub const PUZZLE_UNFOLDING: Unfolding = Unfolding {
+    factor: 5,
+    separator: '?',
+};
+
+#[derive(Clone, Debug)]
+pub struct Record {
+    events: Vec<Event>,
+    states: Vec<State>,
+}
+
+impl Record {
+    pub fn new(input: &str, unfolding: &Unfolding) -> Self {
+        let (springs, groups) = input.split_once(' ').expect("Err: wrong input shape");
+        let groups = vec![groups; unfolding.factor].join(",");
+
+        let mut states: Vec<State> = vec![State::new('.')];
+        for group in groups.split(',').filter(|group| !group.is_empty()) {
+            let group_number = group.parse::<usize>().expect("Err: not a number");
+            for _ in 0..group_number {
+                states.push(State::new('#'));
+            }
+            states.push(State::new('.'));
+        }
+        states.push(State::new('e'));
+
+        let springs = vec![springs; unfolding.factor].join(&unfolding.separator.to_string());
+        let events = springs.chars().map(Event::from).collect();
+        Record { events, states }
+    }
+
+    pub fn count(&self) -> u128 {
+        completions(&self.events, &self.states)[0][0]
+    }
+
+    // Same record, with one (unfolded) cell forced to a damaged spring.
+    // None when that cell is known to be operational, or past the record.
+    pub fn with_hash_at(&self, cell: usize) -> Option<Record> {
+        let mut record = self.clone();
+        let event = record.events.get_mut(cell)?;
+        if event.id == EventId::Dot {
+            return None;
+        }
+        event.id = EventId::Hash;
+        Some(record)
+    }
+
+    pub fn arrangements(&self) -> Arrangements<'_> {
+        let ways = completions(&self.events, &self.states);
+        let stack = if ways[0][0] > 0 {
+            vec![(0, 0, String::new())]
+        } else {
+            vec![]
+        };
+        Arrangements {
+            record: self,
+            ways,
+            stack,
+        }
+    }
+
+    // Uniform over all arrangements: each move is weighted by its completions.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Option<String> {
+        let ways = completions(&self.events, &self.states);
+        if ways[0][0] == 0 {
+            return None;
+        }
+
+        let mut k = 0;
+        let mut arrangement = String::new();
+        for (p, event) in self.events.iter().enumerate() {
+            let moves = event
+                .choices()
+                .iter()
+                .filter_map(|id| next_state(&self.states, k, *id).map(|next| (*id, next)))
+                .filter(|(_, next)| ways[p + 1][*next] > 0)
+                .collect::<Vec<_>>();
+
+            let mut pick = rng.gen_range(0..ways[p][k]);
+            for (id, next) in moves {
+                if pick < ways[p + 1][next] {
+                    arrangement.push(event_char(id));
+                    k = next;
+                    break;
+                }
+                pick -= ways[p + 1][next];
+            }
+        }
+
+        Some(arrangement)
+    }
+}
+
+// Depth first over the automaton, only following moves that can still finish.
+pub struct Arrangements<'a> {
+    record: &'a Record,
+    ways: Vec<Vec<u128>>,
+    stack: Vec<(usize, usize, String)>,
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while let Some((p, k, arrangement)) = self.stack.pop() {
+            let Some(event) = self.record.events.get(p) else {
+                return Some(arrangement);
+            };
+
+            for id in event.choices().iter().rev() {
+                if let Some(next) = next_state(&self.record.states, k, *id) {
+                    if self.ways[p + 1][next] > 0 {
+                        let mut arrangement = arrangement.clone();
+                        arrangement.push(event_char(*id));
+                        self.stack.push((p + 1, next, arrangement));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+pub fn process_lines(lines: &[String], unfolding: &Unfolding) -> u128 {
+    lines
+        .iter()
+        .map(|input| Record::new(input, unfolding).count())
+        .sum::<u128>()
+}
+
+// This one took some de-uglification.
+// And still.
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 12, input);
+    let folded = Unfolding {
+        factor: 1,
+        separator: '?',
+    };
+    for (part, unfolding) in [("one", &folded), ("two", &PUZZLE_UNFOLDING)] {
+        reporter.solve([part], || {
+            let data = input_to_lines(input);
+            [Ok(process_lines(&data, unfolding).to_string())]
+        });
+    }
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 12)), Format::Table);
+}
+
+// size records of 4 to 12 + 4 * difficulty springs. At difficulty 0 every
+// spring is a '?', then a record of length n with k groups of s springs has
+// C(n - s + 1, k) arrangements, and the unfolding stays that simple. From
+// difficulty 1 on, some springs of one arrangement are shown.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let unfolding = PUZZLE_UNFOLDING;
+    let factor = unfolding.factor as u128;
+    let mut lines = vec![];
+    let (mut part_one, mut part_two) = (Some(0_u128), Some(0_u128));
+
+    for _ in 0..knobs.size.max(1) {
+        let len = rng.gen_range(4..=12 + 4 * knobs.difficulty);
+        let mut groups: Vec<usize> = vec![];
+        while groups.iter().sum::<usize>() + groups.len() < len {
+            let group = rng.gen_range(1..=3);
+            if groups.iter().sum::<usize>() + groups.len() + group > len {
+                break;
+            }
+            groups.push(group);
+            if rng.gen_bool(0.3) {
+                break;
+            }
+        }
+        if groups.is_empty() {
+            groups.push(1);
+        }
+
+        let springs = match knobs.difficulty {
+            0 => "?".repeat(len),
+            _ => {
+                // Groups one after another, the spare dots spread at random.
+                let mut dots = vec![1; groups.len() + 1];
+                (dots[0], dots[groups.len()]) = (0, 0);
+                let spare = len - groups.iter().sum::<usize>() - (groups.len() - 1);
+                for _ in 0..spare {
+                    dots[rng.gen_range(0..=groups.len())] += 1;
+                }
+                let mut springs = ".".repeat(dots[0]);
+                for (group, dots) in groups.iter().zip(&dots[1..]) {
+                    springs += &"#".repeat(*group);
+                    springs += &".".repeat(*dots);
+                }
+                springs
+                    .chars()
+                    .map(|ch| if rng.gen_bool(0.6) { '?' } else { ch })
+                    .collect()
+            }
+        };
+        lines.push(format!("{springs} {}", groups.iter().join(",")));
+
+        let (n, s, k) = (
+            len as u128,
+            groups.iter().sum::<usize>() as u128,
+            groups.len() as u128,
+        );
+        let unfolded = (factor * n + factor - 1, factor * s, factor * k);
+        part_one = part_one.and_then(|total| total.checked_add(binomial(n - s + 1, k)?));
+        part_two = part_two.and_then(|total| {
+            total.checked_add(binomial(unfolded.0 - unfolded.1 + 1, unfolded.2)?)
+        });
+    }
+
+    match knobs.difficulty {
+        0 => Generated::new(lines).with_answers(
+            part_one.and_then(|total| i128::try_from(total).ok()),
+            part_two.and_then(|total| i128::try_from(total).ok()),
+        ),
+        _ => Generated::new(lines),
+    }
+}
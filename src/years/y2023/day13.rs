@@ -0,0 +1,243 @@
+use crate::utils::generate::{random_word, Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Mode {
+    Standard,
+    Transposed,
+}
+
+// No one will even see that horror.
+fn transpose(pattern: &[String]) -> Vec<String> {
+    let width = pattern.first().expect("Err: no line").len();
+    let mut transposed: Vec<String> = vec![String::new(); width];
+    for (w, column) in transposed.iter_mut().enumerate() {
+        for line in pattern.iter() {
+            column.push(line.chars().nth(w).expect("Err: no char"));
+        }
+    }
+
+    transposed
+}
+
+// Cell pairs that differ across a mirror placed before column i.
+fn mismatches(line: &str, i: usize) -> Vec<(usize, usize)> {
+    let chars = line.as_bytes();
+    let span_leng = i.min(chars.len() - i);
+    (0..span_leng)
+        .map(|d| (i - 1 - d, i + d))
+        .filter(|(l, r)| chars[*l] != chars[*r])
+        .collect()
+}
+
+// (row, column) pairs, either cell of a pair can be flipped.
+pub type Flip = ((usize, usize), (usize, usize));
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reflection {
+    mode: Mode,
+    index: usize,
+    flips: Vec<Flip>,
+}
+
+impl Reflection {
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn flips(&self) -> &[Flip] {
+        &self.flips
+    }
+
+    pub fn smudges(&self) -> usize {
+        self.flips.len()
+    }
+
+    pub fn score(&self) -> usize {
+        match self.mode {
+            Mode::Standard => self.index,
+            Mode::Transposed => self.index * 100,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    NoLine,
+    Line(Reflection),
+    Ambiguous(Vec<Reflection>),
+}
+
+// Every candidate line, on both axes, with its exact mismatches.
+pub fn reflections(pattern: &[String]) -> Vec<Reflection> {
+    [Mode::Standard, Mode::Transposed]
+        .into_iter()
+        .flat_map(|mode| {
+            let lines = match mode {
+                Mode::Standard => Vec::from(pattern),
+                Mode::Transposed => transpose(pattern),
+            };
+            let len = lines.first().expect("Err: no line").len();
+
+            (1..len)
+                .map(|index| {
+                    let flips = lines
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(h, line)| {
+                            mismatches(line, index)
+                                .into_iter()
+                                .map(move |(l, r)| match mode {
+                                    Mode::Standard => ((h, l), (h, r)),
+                                    Mode::Transposed => ((l, h), (r, h)),
+                                })
+                        })
+                        .collect();
+                    Reflection { mode, index, flips }
+                })
+                .collect::<Vec<Reflection>>()
+        })
+        .collect()
+}
+
+pub fn with_smudges(reflections: &[Reflection], smudges: usize) -> Verdict {
+    let mut found: Vec<Reflection> = reflections
+        .iter()
+        .filter(|reflection| reflection.smudges() == smudges)
+        .cloned()
+        .collect();
+
+    match found.len() {
+        0 => Verdict::NoLine,
+        1 => Verdict::Line(found.remove(0)),
+        _ => Verdict::Ambiguous(found),
+    }
+}
+
+// Err names the first pattern without exactly one line, 1-based.
+pub fn process_lines(lines: &[String], smudges: usize) -> Result<usize, String> {
+    let patterns: Vec<Vec<String>> = lines.iter().fold(vec![Vec::new()], |mut acc, line| {
+        if line.is_empty() {
+            acc.push(Vec::new());
+        } else {
+            acc.last_mut()
+                .expect("Err: no last pattern line")
+                .push(line.clone());
+        }
+        acc
+    });
+
+    // A trailing blank line leaves an empty pattern behind.
+    patterns
+        .iter()
+        .filter(|pattern| !pattern.is_empty())
+        .enumerate()
+        .map(
+            |(k, pattern)| match with_smudges(&reflections(pattern), smudges) {
+                Verdict::Line(reflection) => Ok(reflection.score()),
+                Verdict::NoLine => {
+                    Err(format!("pattern {}: no line with {smudges} smudges", k + 1))
+                }
+                Verdict::Ambiguous(found) => Err(format!(
+                    "pattern {}: {} lines with {smudges} smudges",
+                    k + 1,
+                    found.len()
+                )),
+            },
+        )
+        .sum()
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 13, input);
+    reporter.solve(["one", "two"], || {
+        let data = input_to_lines(input);
+        let part_1 = process_lines(&data, 0);
+        let part_2 = process_lines(&data, 1);
+        [
+            part_1.map(|part_1| part_1.to_string()),
+            part_2.map(|part_2| part_2.to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 13)), Format::Table);
+}
+
+// Mirrored across a column line, then across a row line, then one tile
+// flipped on a row the row line leaves alone: the row line stays perfect, the
+// column line gets one smudge. Lines that show up by accident spoil it.
+fn mirrored_pattern<R: Rng>(rng: &mut R, most: usize) -> Option<(Vec<String>, usize, usize)> {
+    let (height, width) = (rng.gen_range(3..=most), rng.gen_range(3..=most));
+    let mut grid = (0..height)
+        .map(|_| random_word(rng, b"#.", width).into_bytes())
+        .collect_vec();
+
+    let column = rng.gen_range(1..width);
+    let columns = column.min(width - column);
+    for row in grid.iter_mut() {
+        for k in 0..columns {
+            row[column + k] = row[column - 1 - k];
+        }
+    }
+    let row = rng.gen_range(1..height);
+    let rows = row.min(height - row);
+    for k in 0..rows {
+        grid[row + k] = grid[row - 1 - k].clone();
+    }
+
+    let lonely = (0..height)
+        .filter(|i| !(row - rows..row + rows).contains(i))
+        .collect_vec();
+    let i = *lonely.get(rng.gen_range(0..lonely.len().max(1)))?;
+    let j = rng.gen_range(column - columns..column + columns);
+    grid[i][j] = if grid[i][j] == b'#' { b'.' } else { b'#' };
+
+    let mut pattern = grid
+        .into_iter()
+        .map(|row| String::from_utf8(row).expect("Err: not utf8"))
+        .collect_vec();
+    if rng.gen_bool(0.5) {
+        pattern = transpose(&pattern);
+    }
+
+    let found = reflections(&pattern);
+    match (with_smudges(&found, 0), with_smudges(&found, 1)) {
+        (Verdict::Line(perfect), Verdict::Line(smudged)) => {
+            Some((pattern, perfect.score(), smudged.score()))
+        }
+        _ => None,
+    }
+}
+
+// size patterns of up to 7 + 2 * difficulty tiles a side.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let most = 7 + 2 * knobs.difficulty;
+    let mut lines = vec![];
+    let (mut part_one, mut part_two) = (0, 0);
+    for pattern in 0..knobs.size.max(1) {
+        let (found, perfect, smudged) = loop {
+            if let Some(found) = mirrored_pattern(rng, most) {
+                break found;
+            }
+        };
+        if pattern > 0 {
+            lines.push(String::new());
+        }
+        lines.extend(found);
+        part_one += perfect;
+        part_two += smudged;
+    }
+    Generated::new(lines).with_answers(Some(part_one as i128), Some(part_two as i128))
+}
@@ -1,16 +1,25 @@
+#[cfg(not(feature = "nightly"))]
+use crate::utils::compat::TryInsert;
+use crate::utils::generate::{Generated, Knobs};
 use crate::utils::input_process::input_to_lines;
+use crate::utils::render::{Color, Frames, Palette};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::utils::tui;
+use crate::years;
 use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
 use std::collections::HashMap;
 
 #[derive(Clone, Ord, Eq, PartialOrd, PartialEq, Debug, Copy)]
-enum TerrainId {
+pub enum TerrainId {
     Movable,
     Empty,
     Fixed,
 }
 
 #[derive(Clone, Ord, Eq, PartialOrd, PartialEq)]
-enum Compass {
+pub enum Compass {
     North,
     South,
     East,
@@ -18,8 +27,8 @@ enum Compass {
 }
 
 #[derive(Debug, Clone, Copy, Ord, Eq, PartialOrd, PartialEq)]
-struct Terrain {
-    id: TerrainId,
+pub struct Terrain {
+    pub id: TerrainId,
 }
 
 #[derive(Debug)]
@@ -29,7 +38,7 @@ struct ResultData {
 }
 
 impl Terrain {
-    fn new(ch: char) -> Self {
+    pub fn new(ch: char) -> Self {
         match ch {
             'O' => Self {
                 id: TerrainId::Movable,
@@ -58,14 +67,14 @@ fn set_line<T: Copy>(direction: &Compass, zone: &mut [Vec<T>], index: &usize, up
     }
 }
 
-fn get_zone(lines: &mut [String]) -> Vec<Vec<Terrain>> {
+pub fn get_zone(lines: &mut [String]) -> Vec<Vec<Terrain>> {
     lines
         .iter()
         .map(|line| line.chars().map(Terrain::new).collect::<Vec<Terrain>>())
         .collect::<Vec<Vec<Terrain>>>()
 }
 
-fn sort_line(direction: &Compass, line: Vec<Terrain>) -> Vec<Terrain> {
+pub fn sort_line(direction: &Compass, line: Vec<Terrain>) -> Vec<Terrain> {
     let chunks_vec = line
         .split(|&terrain| terrain.id == TerrainId::Fixed)
         .map(|chunks| {
@@ -88,7 +97,7 @@ fn sort_line(direction: &Compass, line: Vec<Terrain>) -> Vec<Terrain> {
     chunks_vec
 }
 
-fn compute_zone_result(zone: &[Vec<Terrain>]) -> usize {
+pub fn compute_zone_result(zone: &[Vec<Terrain>]) -> usize {
     let rates = (1..(zone.len() + 1)).rev();
 
     zone.iter()
@@ -104,7 +113,7 @@ fn compute_zone_result(zone: &[Vec<Terrain>]) -> usize {
         .sum()
 }
 
-fn process_direction(direction: &Compass, zone_updated: &mut [Vec<Terrain>]) {
+pub fn process_direction(direction: &Compass, zone_updated: &mut [Vec<Terrain>]) {
     let width = zone_updated.first().expect("Err: no first").len();
     let height = zone_updated.len();
     let range = match direction {
@@ -129,7 +138,7 @@ fn process_direction(direction: &Compass, zone_updated: &mut [Vec<Terrain>]) {
     });
 }
 
-fn process_lines(lines: &mut [String]) -> usize {
+pub fn process_lines(lines: &mut [String], frames: &mut Option<Frames>) -> usize {
     let mut zone = get_zone(lines);
     let mut results: HashMap<String, ResultData> = HashMap::new();
     let end_value = 1000000000;
@@ -138,6 +147,10 @@ fn process_lines(lines: &mut [String]) -> usize {
         index = i;
         for direction in [Compass::North, Compass::West, Compass::South, Compass::East] {
             process_direction(&direction, &mut zone);
+            if let Some(frames) = frames {
+                let grid = get_string(&zone).lines().map(String::from).collect_vec();
+                frames.record(&grid).expect("Err: could not write frame");
+            }
         }
         let value = compute_zone_result(&zone);
         match results.try_insert(get_string(&zone), ResultData { index, value }) {
@@ -160,6 +173,13 @@ fn process_lines(lines: &mut [String]) -> usize {
     index_result[0].value
 }
 
+// Tilted north once, part 1.
+pub fn north_load(lines: &mut [String]) -> usize {
+    let mut zone = get_zone(lines);
+    process_direction(&Compass::North, &mut zone);
+    compute_zone_result(&zone)
+}
+
 fn get_string(zone: &Vec<Vec<Terrain>>) -> String {
     let mut line = "".to_string();
     for terrains in zone {
@@ -182,9 +202,68 @@ fn get_string(zone: &Vec<Vec<Terrain>>) -> String {
 // Heroically clawed from the Compiler!
 // How sad that
 // They didn not help
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 14, input);
+    reporter.solve(["one", "two"], || {
+        let mut data = input_to_lines(input);
+        [
+            Ok(north_load(&mut data).to_string()),
+            Ok(process_lines(&mut data, &mut None).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
 pub fn run() {
-    let input = "./days/day14/input.txt";
+    report::print(&solve(&years::input_path(2023, 14)), Format::Table);
+}
+
+fn palette() -> Palette {
+    Palette::new(&[('0', [230, 200, 60]), ('#', [90, 90, 110])], [20, 20, 30])
+}
+
+// North load of a rendered platform.
+fn grid_load(grid: &[String]) -> usize {
+    grid.iter()
+        .enumerate()
+        .map(|(i, line)| line.matches('0').count() * (grid.len() - i))
+        .sum()
+}
+
+// One frame per tilt, until the platform cycles.
+pub(crate) fn animate(input: &str, directory: &str, colors: &[Color], scale: Option<usize>) {
+    let mut data = input_to_lines(input);
+    let palette = palette().with(colors);
+    let frames =
+        Frames::new(directory, palette, scale.unwrap_or(4), 1).expect("Err: no frame directory");
+    process_lines(&mut data, &mut Some(frames));
+    println!("\n day14 frames written to {directory}.");
+}
+
+pub(crate) fn visualize(input: &str) {
     let mut data = input_to_lines(input);
-    let result = process_lines(&mut data);
+    let mut frames = Some(Frames::in_memory(palette(), 1));
+    let result = process_lines(&mut data, &mut frames);
+    let frames = frames.expect("Err: no frames");
+    tui::browse("day14 tilts", &frames, &|grid| grid_load(grid).to_string())
+        .expect("Err: terminal");
     println!("\n day14 done with result {result}.");
 }
+
+// A size x size platform, more cube rocks as difficulty grows.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let side = knobs.size.max(1);
+    let cubes = (0.05 * (1 + knobs.difficulty) as f64).min(0.5);
+    let lines = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| match rng.gen::<f64>() {
+                    p if p < cubes => '#',
+                    p if p < cubes + 0.2 => 'O',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    Generated::new(lines)
+}
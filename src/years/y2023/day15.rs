@@ -0,0 +1,332 @@
+use crate::utils::generate::{random_word, Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+const BOXES: usize = 256;
+
+#[derive(Clone, Debug)]
+struct Lens {
+    label: String,
+    foc_len: Option<usize>,
+}
+
+pub trait HolidayHash {
+    fn holiday_hash(&self) -> usize;
+}
+
+impl HolidayHash for str {
+    fn holiday_hash(&self) -> usize {
+        process_label(self)
+    }
+}
+
+impl HolidayHash for String {
+    fn holiday_hash(&self) -> usize {
+        process_label(self)
+    }
+}
+
+// The 256 boxes, in insertion order. Removed entries leave a hole in their
+// box until the box is mostly holes, so the index side-table stays valid.
+#[derive(Clone, Debug)]
+pub struct HolidayHashMap<K, V> {
+    boxes: Vec<Vec<Option<(K, V)>>>,
+    holes: Vec<usize>,
+    index: HashMap<K, (usize, usize)>,
+}
+
+impl<K: HolidayHash + Hash + Eq + Clone, V> Default for HolidayHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: HolidayHash + Hash + Eq + Clone, V> HolidayHashMap<K, V> {
+    pub fn new() -> Self {
+        HolidayHashMap {
+            boxes: (0..BOXES).map(|_| Vec::new()).collect(),
+            holes: vec![0; BOXES],
+            index: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let (i, j) = self.index.get(key)?;
+        self.boxes[*i][*j].as_ref().map(|(_, value)| value)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some((i, j)) = self.index.get(&key) {
+            let (_, old) = self.boxes[*i][*j].as_mut().expect("Err: stale index");
+            return Some(std::mem::replace(old, value));
+        }
+
+        let i = key.holiday_hash();
+        self.index.insert(key.clone(), (i, self.boxes[i].len()));
+        self.boxes[i].push(Some((key, value)));
+        None
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (i, j) = self.index.remove(key)?;
+        let (_, value) = self.boxes[i][j].take().expect("Err: stale index");
+        self.holes[i] += 1;
+        if self.holes[i] * 2 > self.boxes[i].len() {
+            self.compact(i);
+        }
+        Some(value)
+    }
+
+    fn compact(&mut self, i: usize) {
+        self.boxes[i].retain(|slot| slot.is_some());
+        self.holes[i] = 0;
+        for (j, (key, _)) in self.boxes[i].iter().flatten().enumerate() {
+            self.index.insert(key.clone(), (i, j));
+        }
+    }
+
+    // Non-empty boxes, each with its entries in slot order.
+    pub fn boxes(&self) -> impl Iterator<Item = (usize, Vec<(&K, &V)>)> + '_ {
+        self.boxes
+            .iter()
+            .enumerate()
+            .map(|(i, slots)| {
+                let entries = slots
+                    .iter()
+                    .flatten()
+                    .map(|(key, value)| (key, value))
+                    .collect_vec();
+                (i, entries)
+            })
+            .filter(|(_, entries)| !entries.is_empty())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.boxes
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|(key, value)| (key, value))
+    }
+}
+
+impl<K, V> fmt::Display for HolidayHashMap<K, V>
+where
+    K: HolidayHash + Hash + Eq + Clone + fmt::Display,
+    V: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, entries) in self.boxes() {
+            let lenses = entries
+                .iter()
+                .map(|(key, value)| format!("[{key} {value}]"))
+                .join(" ");
+            writeln!(f, "Box {i}: {lenses}")?;
+        }
+        Ok(())
+    }
+}
+
+// Part one, the HASH of every step.
+fn hash_sum(inputs: &[String]) -> usize {
+    inputs
+        .iter()
+        .flat_map(|input| input.split(','))
+        .filter(|step| !step.is_empty())
+        .map(process_label)
+        .sum()
+}
+
+fn process_input(inputs: Vec<String>, trace: bool) -> usize {
+    inputs
+        .iter()
+        .map(|input| {
+            let sequences = input.split(',').collect_vec();
+            process_sequences(&sequences, trace)
+        })
+        .sum()
+}
+
+fn process_label(sequence: &str) -> usize {
+    let mut current_sum = 0;
+    sequence.chars().for_each(|ch| {
+        current_sum += ch as usize;
+        current_sum *= 17;
+        current_sum %= 256;
+    });
+    current_sum
+}
+
+fn parse_sequence(sequence: &str) -> Lens {
+    let toks = sequence.split(['=', '-']).collect_vec();
+    let label = toks.first().expect("Err: no label");
+    let foc_len = match toks.get(1) {
+        Some(t) => ((**t).parse::<usize>()).ok(),
+        None => None,
+    };
+    Lens {
+        label: String::from(*label),
+        foc_len,
+    }
+}
+
+fn compute_result(lenses: &HolidayHashMap<String, usize>) -> usize {
+    lenses
+        .boxes()
+        .map(|(i, entries)| {
+            entries
+                .iter()
+                .enumerate()
+                .map(|(j, (_, foc_len))| (i + 1) * (j + 1) * **foc_len)
+                .sum::<usize>()
+        })
+        .sum::<usize>()
+}
+
+pub fn process_sequences(sequences: &[&str], trace: bool) -> usize {
+    let mut lenses: HolidayHashMap<String, usize> = HolidayHashMap::new();
+    sequences.iter().for_each(|sequence| {
+        let lens = parse_sequence(sequence);
+        match lens.foc_len {
+            // foc_len : plus event
+            Some(foc_len) => {
+                lenses.insert(lens.label, foc_len);
+            }
+            // No foc_len : minus event
+            None => {
+                lenses.remove(&lens.label);
+            }
+        }
+
+        // On stderr, the report on stdout stays whole.
+        if trace {
+            eprintln!("After \"{sequence}\":\n{lenses}");
+        }
+    });
+
+    compute_result(&lenses)
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    solve_with(input, false)
+}
+
+// With `trace`, the boxes after each step of part two.
+pub fn solve_with(input: &str, trace: bool) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 15, input);
+    reporter.solve(["one", "two"], || {
+        let data = input_to_lines(input);
+        [
+            Ok(hash_sum(&data).to_string()),
+            Ok(process_input(data, trace).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 15)), Format::Table);
+}
+
+// size steps on labels from a pool of 5 + 5 * difficulty, so that higher
+// difficulty touches more boxes and removes less often what it inserted.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let pool = (0..5 + 5 * knobs.difficulty)
+        .map(|_| {
+            let len = rng.gen_range(2..=6);
+            random_word(rng, b"abcdefghijklmnopqrstuvwxyz", len)
+        })
+        .collect_vec();
+    let steps = (0..knobs.size.max(1))
+        .map(|_| {
+            let label = &pool[rng.gen_range(0..pool.len())];
+            match rng.gen_bool(0.7) {
+                true => format!("{label}={}", rng.gen_range(1..=9)),
+                false => format!("{label}-"),
+            }
+        })
+        .join(",");
+    Generated::new(vec![steps])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contents(lenses: &HolidayHashMap<String, usize>) -> Vec<(usize, Vec<(&str, usize)>)> {
+        lenses
+            .boxes()
+            .map(|(i, entries)| {
+                let entries = entries
+                    .into_iter()
+                    .map(|(label, focal)| (label.as_str(), *focal))
+                    .collect_vec();
+                (i, entries)
+            })
+            .collect()
+    }
+
+    // The steps of the puzzle's example: a replaced lens keeps its slot, a
+    // removed one leaves the others in order.
+    #[test]
+    fn example_order() {
+        let mut lenses = HolidayHashMap::new();
+        assert_eq!(lenses.insert("rn".to_string(), 1), None);
+        assert_eq!(lenses.remove(&"cm".to_string()), None);
+        lenses.insert("qp".to_string(), 3);
+        lenses.insert("cm".to_string(), 2);
+        assert_eq!(lenses.remove(&"qp".to_string()), Some(3));
+        lenses.insert("pc".to_string(), 4);
+        lenses.insert("ot".to_string(), 9);
+        lenses.insert("ab".to_string(), 5);
+        lenses.remove(&"pc".to_string());
+        lenses.insert("pc".to_string(), 6);
+        assert_eq!(lenses.insert("ot".to_string(), 7), Some(9));
+
+        assert_eq!(
+            contents(&lenses),
+            vec![
+                (0, vec![("rn", 1), ("cm", 2)]),
+                (3, vec![("ot", 7), ("ab", 5), ("pc", 6)]),
+            ]
+        );
+        assert_eq!(lenses.len(), 5);
+        assert_eq!(compute_result(&lenses), 145);
+    }
+
+    // Enough removals in one box to compact it, the order and the index
+    // still right after.
+    #[test]
+    fn order_through_compaction() {
+        let labels = ["rn", "cm", "aju", "akd"];
+        assert!(labels.iter().all(|label| label.holiday_hash() == 0));
+        let mut lenses = HolidayHashMap::new();
+        for (focal, label) in labels.iter().enumerate() {
+            lenses.insert(label.to_string(), focal);
+        }
+        lenses.remove(&"rn".to_string());
+        lenses.remove(&"aju".to_string());
+        lenses.remove(&"cm".to_string());
+        lenses.insert("rn".to_string(), 7);
+
+        assert_eq!(contents(&lenses), vec![(0, vec![("akd", 3), ("rn", 7)])]);
+        assert_eq!(lenses.get(&"akd".to_string()), Some(&3));
+        assert_eq!(lenses.remove(&"akd".to_string()), Some(3));
+        assert_eq!(contents(&lenses), vec![(0, vec![("rn", 7)])]);
+    }
+}
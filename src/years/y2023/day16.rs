@@ -0,0 +1,383 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::utils::input_process::input_to_lines;
+use crate::utils::render::{Color, Frames, Palette};
+use crate::utils::tui;
+use itertools::Itertools;
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Clone, Hash)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Shape {
+    Antislash,
+    Dash,
+    Dot,
+    Pipe,
+    Slash,
+}
+
+#[derive(Debug, Clone)]
+pub struct Place {
+    pub i: usize,
+    pub j: usize,
+    pub shape: Shape,
+    pub directions: Vec<Direction>,
+}
+
+impl Place {
+    pub fn new(i: usize, j: usize, ch: char) -> Self {
+        let shape = match ch {
+            '/' => Shape::Slash,
+            '\\' => Shape::Antislash,
+            '.' => Shape::Dot,
+            '-' => Shape::Dash,
+            '|' => Shape::Pipe,
+            _ => panic!("Err: unknown character"),
+        };
+
+        let beams: Vec<Direction> = Vec::new();
+        Place {
+            i,
+            j,
+            shape,
+            directions: beams,
+        }
+    }
+}
+
+pub struct Map {
+    pub places: Vec<Vec<Place>>,
+    pub height: usize,
+    pub width: usize,
+}
+
+// Ew. Should bit fiddle instead, perhaps, but then ew.
+fn get_slash_directions(direction: Direction) -> Vec<Direction> {
+    match direction {
+        Direction::North => vec![Direction::East],
+        Direction::West => vec![Direction::South],
+        Direction::South => vec![Direction::West],
+        Direction::East => vec![Direction::North],
+    }
+}
+
+fn get_antislash_directions(direction: Direction) -> Vec<Direction> {
+    match direction {
+        Direction::North => vec![Direction::West],
+        Direction::East => vec![Direction::South],
+        Direction::South => vec![Direction::East],
+        Direction::West => vec![Direction::North],
+    }
+}
+
+fn get_dot_direction(direction: Direction) -> Vec<Direction> {
+    match direction {
+        Direction::North => vec![Direction::North],
+        Direction::East => vec![Direction::East],
+        Direction::South => vec![Direction::South],
+        Direction::West => vec![Direction::West],
+    }
+}
+
+fn get_pipe_direction(direction: Direction) -> Vec<Direction> {
+    match direction {
+        Direction::North => vec![Direction::North],
+        Direction::East => vec![Direction::North, Direction::South],
+        Direction::South => vec![Direction::South],
+        Direction::West => vec![Direction::North, Direction::South],
+    }
+}
+
+fn get_dash_direction(direction: Direction) -> Vec<Direction> {
+    match direction {
+        Direction::North => vec![Direction::East, Direction::West],
+        Direction::East => vec![Direction::East],
+        Direction::South => vec![Direction::East, Direction::West],
+        Direction::West => vec![Direction::West],
+    }
+}
+
+fn get_dir_shape(direction: Direction, shape: &Shape) -> Vec<Direction> {
+    match shape {
+        Shape::Antislash => get_antislash_directions(direction),
+        Shape::Dash => get_dash_direction(direction),
+        Shape::Dot => get_dot_direction(direction),
+        Shape::Slash => get_slash_directions(direction),
+        Shape::Pipe => get_pipe_direction(direction),
+    }
+}
+
+impl Place {
+    pub fn get_new_directions(&mut self) -> Vec<Direction> {
+        let mut directions_next: Vec<Direction> = Vec::new();
+        while let Some(directions) = self.directions.pop() {
+            let new_directions = get_dir_shape(directions, &self.shape);
+            directions_next.extend(new_directions);
+        }
+        directions_next
+    }
+}
+
+fn get_place_direction(
+    mut i: usize,
+    mut j: usize,
+    direction: &Direction,
+    map: &Map,
+) -> Option<Place> {
+    match direction {
+        Direction::North => {
+            i = i.checked_sub(1)?;
+        }
+        Direction::South => {
+            i += 1;
+            if i >= map.height {
+                return None;
+            }
+        }
+        Direction::East => {
+            j += 1;
+            if j >= map.width {
+                return None;
+            }
+        }
+        Direction::West => {
+            j = j.checked_sub(1)?;
+        }
+    }
+    let new_place = get_place(i, j, map);
+
+    Some(new_place.clone())
+}
+
+pub fn get_place(i: usize, j: usize, map: &Map) -> Place {
+    map.places[i][j].clone()
+}
+
+fn shape_char(shape: &Shape) -> char {
+    match shape {
+        Shape::Slash => '/',
+        Shape::Antislash => '\\',
+        Shape::Dot => '.',
+        Shape::Dash => '-',
+        Shape::Pipe => '|',
+    }
+}
+
+fn energized_lines(map: &Map, visited_places: &HashSet<(usize, usize, Direction)>) -> Vec<String> {
+    let mut lines = map
+        .places
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|place| shape_char(&place.shape))
+                .collect_vec()
+        })
+        .collect_vec();
+    for (i, j, _) in visited_places {
+        lines[*i][*j] = '#';
+    }
+    lines.iter().map(|line| line.iter().collect()).collect()
+}
+
+pub fn process_start(
+    starting_place: &Place,
+    starting_direction: Direction,
+    map: &Map,
+    frames: &mut Option<Frames>,
+) -> usize {
+    let mut visited_places: HashSet<(usize, usize, Direction)> = HashSet::new();
+    let mut starting_place = (*starting_place).clone();
+    starting_place.directions.push(starting_direction.clone());
+    visited_places.insert((starting_place.i, starting_place.j, starting_direction));
+
+    // One wave of beam heads at a time, a frame after each.
+    let mut places = vec![starting_place];
+    while !places.is_empty() {
+        let mut next_places = vec![];
+        for mut place in places {
+            let next_directions = place.get_new_directions();
+            for direction in next_directions {
+                if let Some(mut next_place) =
+                    get_place_direction(place.i, place.j, &direction, map).clone()
+                {
+                    if visited_places.contains(&(next_place.i, next_place.j, direction.clone())) {
+                        continue;
+                    }
+
+                    visited_places.insert((next_place.i, next_place.j, direction.clone()));
+                    next_place.directions.push(direction.clone());
+                    next_places.push(next_place);
+                }
+            }
+        }
+        places = next_places;
+
+        if let Some(frames) = frames {
+            frames
+                .record(&energized_lines(map, &visited_places))
+                .expect("Err: could not write frame");
+        }
+    }
+
+    let visited = visited_places
+        .iter()
+        .map(|(i, j, _)| (i, j))
+        .unique()
+        .collect_vec();
+
+    visited.len()
+}
+
+pub fn get_map(inputs: &[String]) -> Map {
+    let width = inputs.first().expect("Err: no input").len();
+    let height = inputs.len();
+
+    let places = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, ch)| Place::new(i, j, ch))
+                .collect_vec()
+        })
+        .collect_vec();
+
+    Map {
+        places,
+        height,
+        width,
+    }
+}
+
+pub fn process_input(inputs: Vec<String>) -> usize {
+    let map = get_map(&inputs);
+    let Map { height, width, .. } = map;
+
+    let mut results: Vec<usize> = Vec::new();
+    for j in 0..width {
+        let starting_place = get_place(0, j, &map).clone();
+        let starting_direction = Direction::South;
+        results.push(process_start(
+            &starting_place,
+            starting_direction,
+            &map,
+            &mut None,
+        ));
+
+        let starting_place = get_place(height - 1, j, &map).clone();
+        let starting_direction = Direction::North;
+        results.push(process_start(
+            &starting_place,
+            starting_direction,
+            &map,
+            &mut None,
+        ));
+    }
+
+    for i in 0..height {
+        let starting_place = get_place(i, 0, &map).clone();
+        let starting_direction = Direction::East;
+        results.push(process_start(
+            &starting_place,
+            starting_direction,
+            &map,
+            &mut None,
+        ));
+
+        let starting_place = get_place(i, width - 1, &map).clone();
+        let starting_direction = Direction::West;
+        results.push(process_start(
+            &starting_place,
+            starting_direction,
+            &map,
+            &mut None,
+        ));
+    }
+
+    *results.iter().max().expect("Err: no max")
+}
+
+// The beam from the top left corner heading east, part 1.
+pub fn top_left(inputs: &[String]) -> usize {
+    let map = get_map(inputs);
+    process_start(&get_place(0, 0, &map), Direction::East, &map, &mut None)
+}
+
+// Yuck.
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 16, input);
+    reporter.solve(["one", "two"], || {
+        let data = input_to_lines(input);
+        [
+            Ok(top_left(&data).to_string()),
+            Ok(process_input(data).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 16)), Format::Table);
+}
+
+fn palette() -> Palette {
+    Palette::new(&[('#', [250, 220, 80])], [30, 30, 45])
+}
+
+// The part 1 beam, from the top left corner heading east.
+pub(crate) fn animate(input: &str, directory: &str, colors: &[Color], scale: Option<usize>) {
+    let data = input_to_lines(input);
+    let map = get_map(&data);
+    let palette = palette().with(colors);
+    let frames =
+        Frames::new(directory, palette, scale.unwrap_or(4), 1).expect("Err: no frame directory");
+    let starting_place = get_place(0, 0, &map);
+    let result = process_start(&starting_place, Direction::East, &map, &mut Some(frames));
+    println!("\n day16 frames written to {directory}, {result} tiles energized.");
+}
+
+pub(crate) fn visualize(input: &str) {
+    let data = input_to_lines(input);
+    let map = get_map(&data);
+    let mut frames = Some(Frames::in_memory(palette(), 1));
+    let starting_place = get_place(0, 0, &map);
+    let result = process_start(&starting_place, Direction::East, &map, &mut frames);
+    let frames = frames.expect("Err: no frames");
+    tui::browse("day16 beam", &frames, &|grid| {
+        grid.iter()
+            .map(|line| line.matches('#').count())
+            .sum::<usize>()
+            .to_string()
+    })
+    .expect("Err: terminal");
+    println!("\n day16 done with result {result}.");
+}
+
+// A size x size contraption, more mirrors and splitters as difficulty grows.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let side = knobs.size.max(1);
+    let devices = (0.05 * (1 + knobs.difficulty) as f64).min(0.8);
+    let lines = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| match rng.gen_bool(devices) {
+                    true => b"/\\|-"[rng.gen_range(0..4)] as char,
+                    false => '.',
+                })
+                .collect()
+        })
+        .collect();
+    Generated::new(lines)
+}
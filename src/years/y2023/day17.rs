@@ -0,0 +1,261 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::render::{Frames, Palette};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::utils::tui;
+use crate::years;
+use itertools::Itertools;
+use pathfinding::prelude::dijkstra;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Least and most blocks in a straight line: the crucible, then the ultra one.
+pub const CRUCIBLE: (usize, usize) = (1, 3);
+pub const ULTRA_CRUCIBLE: (usize, usize) = (4, 10);
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Clone, Hash, Copy)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Place {
+    pub i: usize,
+    pub j: usize,
+    pub cost: usize,
+    pub same_direction: usize,
+    pub direction: Option<Direction>,
+}
+
+impl Place {
+    pub fn new(i: usize, j: usize, cost: usize) -> Self {
+        Place {
+            i,
+            j,
+            cost,
+            same_direction: 0,
+            direction: None,
+        }
+    }
+}
+
+fn get_opposite(lhs: &Direction) -> Direction {
+    match lhs {
+        Direction::North => Direction::South,
+        Direction::East => Direction::West,
+        Direction::West => Direction::East,
+        Direction::South => Direction::North,
+    }
+}
+
+fn get_at_direction(place_from: &Place, direction: &Direction, map: &mut Map) -> Option<Place> {
+    let Place { mut i, mut j, .. } = place_from;
+
+    if let Some(direction_from) = place_from.direction {
+        if direction_from == get_opposite(direction) {
+            return None;
+        }
+    }
+
+    match direction {
+        Direction::North => {
+            i = i.checked_sub(1)?;
+        }
+        Direction::South => {
+            i += 1;
+            if i >= map.height {
+                return None;
+            }
+        }
+        Direction::East => {
+            j += 1;
+            if j >= map.width {
+                return None;
+            }
+        }
+        Direction::West => {
+            j = j.checked_sub(1)?;
+        }
+    }
+
+    let (least, most) = map.straight;
+    let place = &mut map.places[i][j];
+
+    if (((map.width) - least)..(map.width - 1)).contains(&j)
+        && (((map.height) - least)..(map.height - 1)).contains(&i)
+    {
+        return None;
+    }
+
+    let same_direction: usize;
+
+    if place_from.direction == Some(*direction) {
+        same_direction = place_from.same_direction + 1;
+        if same_direction > most {
+            return None;
+        }
+    } else {
+        if place_from.same_direction < least && place_from.direction.is_some() {
+            return None;
+        }
+        same_direction = 1;
+    }
+
+    place.same_direction = same_direction;
+    place.direction = Some(*direction);
+    Some(place.clone())
+}
+
+fn get_candidates(place_from: &Place, map: &mut Map) -> Vec<(Place, usize)> {
+    let candidate_directions = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+    let candidates = candidate_directions
+        .iter()
+        .filter_map(|direction| get_at_direction(place_from, direction, map))
+        .map(|place| (place.clone(), place.cost))
+        .collect_vec();
+    candidates
+}
+
+pub struct Map {
+    pub places: Vec<Vec<Place>>,
+    pub height: usize,
+    pub width: usize,
+    pub straight: (usize, usize),
+}
+
+// Expanded cells show as '*' over the heat loss digits.
+fn frontier_lines(inputs: &[String], expanded: &[Vec<bool>]) -> Vec<String> {
+    inputs
+        .iter()
+        .zip(expanded.iter())
+        .map(|(line, expanded)| {
+            line.chars()
+                .zip(expanded.iter())
+                .map(|(ch, expanded)| if *expanded { '*' } else { ch })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn process_input(
+    inputs: Vec<String>,
+    straight: (usize, usize),
+    frames: &mut Option<Frames>,
+) -> usize {
+    let width = inputs.first().expect("Err: no input").len();
+    let height = inputs.len();
+    let places = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, ch)| {
+                    Place::new(
+                        i,
+                        j,
+                        ch.to_string().parse::<usize>().expect("Err: not an usize"),
+                    )
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let mut map = Map {
+        places,
+        height,
+        width,
+        straight,
+    };
+
+    let mut starting_place = map.places[0][0].clone();
+    starting_place.direction = None;
+    let mut expanded = vec![vec![false; width]; height];
+    let Some((_, cost)) = dijkstra(
+        &starting_place,
+        |p| {
+            if let Some(frames) = frames {
+                expanded[p.i][p.j] = true;
+                frames
+                    .record_with(|| frontier_lines(&inputs, &expanded))
+                    .expect("Err: could not write frame");
+            }
+            get_candidates(p, &mut map)
+        },
+        |b| b.i == (height - 1) && b.j == (width - 1),
+    ) else {
+        panic!("no path found")
+    };
+
+    if let Some(frames) = frames {
+        frames
+            .finish(&frontier_lines(&inputs, &expanded))
+            .expect("Err: could not write frame");
+    }
+
+    cost
+}
+
+// New rule : I can use libs, after all, when I am asked something as fun as "implement a Djikstra".
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 17, input);
+    reporter.solve(["one", "two"], || {
+        let data = input_to_lines(input);
+        [
+            Ok(process_input(data.clone(), CRUCIBLE, &mut None).to_string()),
+            Ok(process_input(data, ULTRA_CRUCIBLE, &mut None).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 17)), Format::Table);
+}
+
+pub(crate) fn visualize(input: &str) {
+    let data = input_to_lines(input);
+    let ramp = ('1'..='9')
+        .map(|ch| {
+            let heat = ch.to_digit(10).expect("Err: not a digit") as u8 * 25;
+            (ch, [heat, heat / 3, 40])
+        })
+        .chain([('*', [90, 230, 250])])
+        .collect_vec();
+    // One frame every 500 expansions.
+    let mut frames = Some(Frames::in_memory(Palette::new(&ramp, [0, 0, 0]), 500));
+    let result = process_input(data, ULTRA_CRUCIBLE, &mut frames);
+    let frames = frames.expect("Err: no frames");
+    tui::browse("day17 dijkstra", &frames, &|grid| {
+        let expanded = grid
+            .iter()
+            .map(|line| line.matches('*').count())
+            .sum::<usize>();
+        format!("{expanded} cells expanded, final heat loss {result}")
+    })
+    .expect("Err: terminal");
+    println!("\n day17 done with result {result}.");
+}
+
+// A size x size city, heat losses from 1 to 3 + 3 * difficulty (at most 9).
+// At least 5 wide: the ultra crucible needs 4 blocks to go anywhere.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let side = knobs.size.max(5);
+    let top = (3 + 3 * knobs.difficulty).min(9) as u32;
+    let lines = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| char::from_digit(rng.gen_range(1..=top), 10).expect("Err: digit"))
+                .collect()
+        })
+        .collect();
+    Generated::new(lines)
+}
@@ -0,0 +1,236 @@
+use crate::utils::generate::{shoelace, skyline, Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+
+fn get_direction(st: &str) -> Direction {
+    match st {
+        "0" | "R" => Direction::Right,
+        "1" | "D" => Direction::Down,
+        "2" | "L" => Direction::Left,
+        "3" | "U" => Direction::Up,
+        _ => panic!("Unknown Direction"),
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Clone, Hash, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+impl Direction {
+    fn is_opposite(&self, other: &Direction) -> bool {
+        matches!(
+            (self, other),
+            (Direction::Up, Direction::Down)
+                | (Direction::Down, Direction::Up)
+                | (Direction::Right, Direction::Left)
+                | (Direction::Left, Direction::Right)
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Encoding {
+    // R 6 (#70c710): "R 6", part 1.
+    Plain,
+    // R 6 (#70c710): "70c71" and "0", part 2.
+    Hex,
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Clone, Hash, Copy)]
+struct Step {
+    direction: Direction,
+    number: i128,
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Clone, Hash, Copy)]
+struct Place {
+    i: i128,
+    j: i128,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum PlanError {
+    NotClosed,
+    // Indices of two dig steps that touch.
+    SelfIntersecting(usize, usize),
+}
+
+fn line_to_step(line: &str, encoding: Encoding) -> Step {
+    match encoding {
+        Encoding::Plain => {
+            let (direction, number) = line
+                .split(' ')
+                .take(2)
+                .collect_tuple()
+                .expect("Err: no direction and distance");
+            Step {
+                direction: get_direction(direction),
+                number: number.parse::<i128>().expect("Err: distance not number"),
+            }
+        }
+        Encoding::Hex => {
+            let (_, hex_direction) = line.split_once('#').expect("Err: hex not found");
+            let direction = get_direction(&hex_direction[5..6]);
+            let number =
+                i128::from_str_radix(&hex_direction[0..5], 16).expect("Err: hex not number");
+            Step { direction, number }
+        }
+    }
+}
+
+fn apply_step(place: &Place, step: &Step) -> Place {
+    let Place { i, j } = *place;
+    let Step { direction, number } = step;
+
+    match direction {
+        Direction::Up => Place { i: i - number, j },
+        Direction::Down => Place { i: i + number, j },
+        Direction::Right => Place { i, j: j + number },
+        Direction::Left => Place { i, j: j - number },
+    }
+}
+
+// Vertices only, first one is the origin, repeated at the end.
+fn get_vertices(steps: &[Step]) -> Vec<Place> {
+    steps
+        .iter()
+        .fold(vec![Place { i: 0, j: 0 }], |mut acc, step| {
+            let last = acc.last().expect("Err: get place");
+            acc.push(apply_step(last, step));
+            acc
+        })
+}
+
+fn segments_touch(lhs: (&Place, &Place), rhs: (&Place, &Place)) -> bool {
+    let (a0, a1) = lhs;
+    let (b0, b1) = rhs;
+    a0.i.min(a1.i) <= b0.i.max(b1.i)
+        && b0.i.min(b1.i) <= a0.i.max(a1.i)
+        && a0.j.min(a1.j) <= b0.j.max(b1.j)
+        && b0.j.min(b1.j) <= a0.j.max(a1.j)
+}
+
+fn check_plan(steps: &[Step], vertices: &[Place]) -> Result<(), PlanError> {
+    if vertices.first() != vertices.last() {
+        return Err(PlanError::NotClosed);
+    }
+
+    let len = steps.len();
+    for k in 0..len {
+        for l in (k + 1)..len {
+            let adjacent = l == k + 1 || (k == 0 && l == len - 1);
+            if adjacent {
+                // Neighbours share a corner, they only overlap when backtracking.
+                let (first, second) = if l == k + 1 { (k, l) } else { (l, k) };
+                if steps[first].direction.is_opposite(&steps[second].direction) {
+                    return Err(PlanError::SelfIntersecting(k, l));
+                }
+                continue;
+            }
+
+            let lhs = (&vertices[k], &vertices[k + 1]);
+            let rhs = (&vertices[l], &vertices[l + 1]);
+            if segments_touch(lhs, rhs) {
+                return Err(PlanError::SelfIntersecting(k, l));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Shoelace for the area through the cell centers, then Pick for the cells:
+// interior + boundary = area + boundary / 2 + 1.
+fn lagoon_size(steps: &[Step]) -> Result<i128, PlanError> {
+    let vertices = get_vertices(steps);
+    check_plan(steps, &vertices)?;
+
+    let area: i128 = vertices
+        .iter()
+        .tuple_windows()
+        .map(|(place0, place1)| place0.i * place1.j - place1.i * place0.j)
+        .sum::<i128>()
+        .abs()
+        / 2;
+
+    let boundary: i128 = steps.iter().map(|step| step.number).sum();
+
+    Ok(area + (boundary / 2) + 1)
+}
+
+pub fn process_input(lines: &[String], encoding: Encoding) -> Result<i128, PlanError> {
+    let steps = lines
+        .iter()
+        .map(|line| line_to_step(line, encoding))
+        .collect_vec();
+
+    lagoon_size(&steps)
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 18, input);
+    for (part, encoding) in [("one", Encoding::Plain), ("two", Encoding::Hex)] {
+        reporter.solve([part], || {
+            let data = input_to_lines(input);
+            [process_input(&data, encoding)
+                .map(|result| result.to_string())
+                .map_err(|error| format!("{encoding:?} plan: {error:?}"))]
+        });
+    }
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 18)), Format::Table);
+}
+
+fn plan_lines(corners: &[(i64, i64)]) -> Vec<(char, i64)> {
+    corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .map(|((i0, j0), (i1, j1))| {
+            let direction = match ((i1 - i0).signum(), (j1 - j0).signum()) {
+                (-1, _) => 'U',
+                (1, _) => 'D',
+                (_, 1) => 'R',
+                _ => 'L',
+            };
+            (direction, (i1 - i0).abs() + (j1 - j0).abs())
+        })
+        .collect()
+}
+
+// Two skyline loops with 1 + difficulty blocks: a size x 2size one in plain,
+// a much bigger one in the colors.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let height = knobs.size.max(3) as i64;
+    let blocks = 1 + knobs.difficulty;
+    let plain = skyline(rng, blocks, height, 2 * height);
+    // Steps must fit in five hex digits.
+    let big = (1000 * height).min(400_000);
+    // As many blocks as the plain one got, the plans go line by line.
+    let hex = skyline(rng, (plain.len() - 2) / 2, big, 2 * big);
+
+    let lines = plan_lines(&plain)
+        .iter()
+        .zip(plan_lines(&hex))
+        .map(|((direction, len), (hex_direction, hex_len))| {
+            let digit = "RDLU".find(hex_direction).expect("Err: no direction");
+            format!("{direction} {len} (#{hex_len:05x}{digit})")
+        })
+        .collect();
+
+    // Pick: the inside plus the trench itself.
+    let lagoon = |corners: &[(i64, i64)]| {
+        let (twice_area, boundary) = shoelace(corners);
+        (twice_area + boundary) as i128 / 2 + 1
+    };
+    Generated::new(lines).with_answers(Some(lagoon(&plain)), Some(lagoon(&hex)))
+}
@@ -1,18 +1,25 @@
+use crate::utils::generate::{random_word, Generated, Knobs};
 use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use regex::Regex;
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 #[derive(Debug)]
-struct Condition {
-    variable: char,
-    operand_char: char,
-    threshold: u64,
-    next_name: String,
+pub struct Condition {
+    pub variable: char,
+    pub operand_char: char,
+    pub threshold: u64,
+    pub next_name: String,
 }
 
 // Should macro these
 impl Condition {
-    fn update_ranges_not_met(&self, ranges: &mut Ranges) {
+    pub fn update_ranges_not_met(&self, ranges: &mut Ranges) {
         match self.variable {
             'x' => {
                 if self.operand_char == '<' {
@@ -46,7 +53,7 @@ impl Condition {
         }
     }
 
-    fn update_ranges_met(&self, ranges: &mut Ranges) {
+    pub fn update_ranges_met(&self, ranges: &mut Ranges) {
         match self.variable {
             'x' => {
                 if self.operand_char == '<' {
@@ -81,24 +88,39 @@ impl Condition {
     }
 }
 
+// Ratings of one part, by category.
+pub type Part = HashMap<char, u64>;
+
+impl Condition {
+    // The last condition of a workflow has no category and always holds.
+    pub fn is_met(&self, part: &Part) -> bool {
+        match (part.get(&self.variable), self.operand_char) {
+            (None, _) => true,
+            (Some(rating), '<') => *rating < self.threshold,
+            (Some(rating), _) => *rating > self.threshold,
+        }
+    }
+}
+
 #[derive(Debug)]
-struct Ranges {
-    x: (u64, u64),
-    m: (u64, u64),
-    a: (u64, u64),
-    s: (u64, u64),
+pub struct Ranges {
+    pub x: (u64, u64),
+    pub m: (u64, u64),
+    pub a: (u64, u64),
+    pub s: (u64, u64),
 }
 
 impl Ranges {
-    fn get_total(&self) -> u64 {
-        (self.x.1 - self.x.0 + 1)
-            * (self.m.1 - self.m.0 + 1)
-            * (self.a.1 - self.a.0 + 1)
-            * (self.s.1 - self.s.0 + 1)
+    // Conditions along a path can leave a range empty.
+    pub fn get_total(&self) -> u64 {
+        [self.x, self.m, self.a, self.s]
+            .iter()
+            .map(|(low, high)| (high + 1).saturating_sub(*low))
+            .product()
     }
 }
 
-fn parse_rules(lines: &[String]) -> HashMap<String, Vec<Condition>> {
+pub fn parse_rules(lines: &[String]) -> HashMap<String, Vec<Condition>> {
     let mut rules: HashMap<String, Vec<Condition>> = HashMap::new();
     // I still do not like regexps, mind.
     let re: Regex = Regex::new(r"^([xmas])([<>])(\d+):(\w+)$").unwrap();
@@ -134,7 +156,50 @@ fn parse_rules(lines: &[String]) -> HashMap<String, Vec<Condition>> {
     rules
 }
 
-fn process_rule(starting_point: (&str, usize), rules: &HashMap<String, Vec<Condition>>) -> Ranges {
+// {x=787,m=2655,a=1222,s=2876}
+pub fn parse_parts(lines: &[String]) -> Vec<Part> {
+    lines
+        .iter()
+        .map(|line| {
+            line.trim_matches(['{', '}'])
+                .split(',')
+                .map(|rating| {
+                    let (category, value) = rating.split_once('=').expect("Err: no =");
+                    (
+                        category.chars().next().expect("Err: no category"),
+                        value.parse::<u64>().expect("Err: not a rating"),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Sum of the ratings of the parts the workflows accept, part 1.
+pub fn accepted_ratings(lines: &[String]) -> u64 {
+    let index = lines.iter().position(|line| line.is_empty()).unwrap();
+    let rules = parse_rules(&lines[0..index]);
+    parse_parts(&lines[index + 1..])
+        .iter()
+        .filter(|part| {
+            let mut name = "in";
+            while name != "A" && name != "R" {
+                name = &rules[name]
+                    .iter()
+                    .find(|condition| condition.is_met(part))
+                    .expect("Err: no condition met")
+                    .next_name;
+            }
+            name == "A"
+        })
+        .map(|part| part.values().sum::<u64>())
+        .sum()
+}
+
+pub fn process_rule(
+    starting_point: (&str, usize),
+    rules: &HashMap<String, Vec<Condition>>,
+) -> Ranges {
     let mut ranges = Ranges {
         x: (1, 4000),
         m: (1, 4000),
@@ -170,7 +235,7 @@ fn process_rule(starting_point: (&str, usize), rules: &HashMap<String, Vec<Condi
     ranges
 }
 
-fn process_lines(lines: &[String]) -> u64 {
+pub fn process_lines(lines: &[String]) -> u64 {
     let index = lines.iter().position(|line| line.is_empty()).unwrap();
     let rules = lines[0..index].to_vec();
     let rules = parse_rules(&rules);
@@ -190,9 +255,62 @@ fn process_lines(lines: &[String]) -> u64 {
         .sum()
 }
 
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 19, input);
+    reporter.solve(["one", "two"], || {
+        let lines = input_to_lines(input);
+        [
+            Ok(accepted_ratings(&lines).to_string()),
+            Ok(process_lines(&lines).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
 pub fn run() {
-    let input = "./days/day19/input.txt";
-    let lines = input_to_lines(input);
-    let result = process_lines(&lines);
-    println!("\n day19 done with result {result}.");
+    report::print(&solve(&years::input_path(2023, 19)), Format::Table);
+}
+
+// A tree of workflows down to depth 2 + difficulty, and size parts.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let depth = 2 + knobs.difficulty;
+    let mut names = HashSet::from(["in".to_string()]);
+    let mut pending = vec![("in".to_string(), 0)];
+    let mut workflows = vec![];
+
+    while let Some((name, level)) = pending.pop() {
+        let mut target = |rng: &mut StdRng| {
+            if level + 1 < depth && rng.gen_bool(0.6) {
+                loop {
+                    let len = rng.gen_range(2..=3);
+                    let next = random_word(rng, b"abcdefghijklmnopqrstuvwxyz", len);
+                    if names.insert(next.clone()) {
+                        pending.push((next.clone(), level + 1));
+                        return next;
+                    }
+                }
+            }
+            ["A", "R"][rng.gen_range(0..2)].to_string()
+        };
+
+        let mut rules = vec![];
+        for _ in 0..rng.gen_range(1..=3) {
+            let category = b"xmas"[rng.gen_range(0..4)] as char;
+            let comparison = ['<', '>'][rng.gen_range(0..2)];
+            let value = rng.gen_range(1..4000);
+            rules.push(format!("{category}{comparison}{value}:{}", target(rng)));
+        }
+        rules.push(target(rng));
+        let rules = rules.join(",");
+        workflows.push(format!("{name}{{{rules}}}"));
+    }
+    workflows.shuffle(rng);
+
+    let mut lines = workflows;
+    lines.push(String::new());
+    for _ in 0..knobs.size.max(1) {
+        let [x, m, a, s] = [0; 4].map(|_| rng.gen_range(1..=4000));
+        lines.push(format!("{{x={x},m={m},a={a},s={s}}}"));
+    }
+    Generated::new(lines)
 }
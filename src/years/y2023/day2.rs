@@ -0,0 +1,193 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+// Cubes per colour, any colour goes.
+pub type Bag = HashMap<String, u32>;
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: u32,
+    draws: Vec<Bag>,
+}
+
+impl Game {
+    pub fn minimal_bag(&self) -> Bag {
+        let mut bag = Bag::new();
+        for draw in self.draws.iter() {
+            for (color, number) in draw {
+                let max = bag.entry(color.clone()).or_insert(0);
+                *max = (*max).max(*number);
+            }
+        }
+        bag
+    }
+
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.minimal_bag()
+            .iter()
+            .all(|(color, number)| bag.get(color).unwrap_or(&0) >= number)
+    }
+
+    // Product over the colours of the bag, a colour never drawn makes it 0.
+    pub fn power(&self, bag: &Bag) -> u64 {
+        let minimal = self.minimal_bag();
+        bag.keys()
+            .map(|color| *minimal.get(color).unwrap_or(&0) as u64)
+            .product()
+    }
+}
+
+pub fn puzzle_bag() -> Bag {
+    Bag::from([
+        ("blue".to_string(), 14),
+        ("green".to_string(), 13),
+        ("red".to_string(), 12),
+    ])
+}
+
+pub fn process_line(line: &str) -> Game {
+    // Do _not_ like regexps.
+    let mut game: Vec<&str> = line.split([':', ';']).collect();
+    let mut subsets: Vec<&str> = game.remove(0).split(' ').collect();
+
+    let id = subsets
+        .remove(1)
+        .parse::<u32>()
+        .expect("Err: Could not parse number");
+
+    let draws = game
+        .iter()
+        .map(|subset| {
+            subset
+                .split([','])
+                .map(|cube| {
+                    let mut number_color: Vec<&str> = cube.split(' ').collect();
+                    let color = number_color.pop().expect("No color");
+                    let number = number_color
+                        .pop()
+                        .expect("No number")
+                        .parse::<u32>()
+                        .expect("Err: Could not parse number");
+                    (color.to_string(), number)
+                })
+                .collect()
+        })
+        .collect();
+
+    Game { id, draws }
+}
+
+pub fn possible_ids(games: &[Game], bag: &Bag) -> Vec<u32> {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .collect()
+}
+
+// Games that only become possible once k cubes of that colour are added.
+pub fn unlocked_ids(games: &[Game], bag: &Bag, color: &str, k: u32) -> Vec<u32> {
+    let mut bigger = bag.clone();
+    *bigger.entry(color.to_string()).or_insert(0) += k;
+
+    games
+        .iter()
+        .filter(|game| !game.is_possible(bag) && game.is_possible(&bigger))
+        .map(|game| game.id)
+        .collect()
+}
+
+pub fn total_power(games: &[Game], bag: &Bag) -> u64 {
+    games.iter().map(|game| game.power(bag)).sum()
+}
+
+fn day2(input: &str) -> Vec<Game> {
+    let fpath = Path::new(input);
+    let file = File::open(fpath).unwrap();
+    let lines = io::BufReader::new(file).lines();
+
+    lines
+        .map(|line| process_line(&line.expect("Err: could not use line")))
+        .collect()
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 2, input);
+    reporter.solve(["one", "two"], || {
+        let games = day2(input);
+        let bag = puzzle_bag();
+        let possible: u32 = possible_ids(&games, &bag).iter().sum();
+        [
+            Ok(possible.to_string()),
+            Ok(total_power(&games, &bag).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 2)), Format::Table);
+}
+
+// size games, difficulty adds draws and cubes.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let colors = ["red", "green", "blue"];
+    let bag = puzzle_bag();
+    let mut lines = vec![];
+    let (mut part_one, mut part_two) = (0, 0);
+
+    for id in 1..=knobs.size.max(1) {
+        let count = rng.gen_range(1..=3 + knobs.difficulty);
+        let maxima = colors.map(|_| rng.gen_range(1..=10 + 3 * knobs.difficulty as u32));
+
+        // Each color shows its maximum in one draw, and less in some others.
+        let mut draws: Vec<Vec<(u32, &str)>> = vec![vec![]; count];
+        for (color, maximum) in colors.iter().zip(maxima) {
+            let hit = rng.gen_range(0..count);
+            for (k, draw) in draws.iter_mut().enumerate() {
+                if k == hit {
+                    draw.push((maximum, color));
+                } else if rng.gen_bool(0.5) {
+                    draw.push((rng.gen_range(1..=maximum), color));
+                }
+            }
+        }
+        for draw in draws.iter_mut() {
+            if draw.is_empty() {
+                let (color, maximum) = (colors[0], maxima[0]);
+                draw.push((rng.gen_range(1..=maximum), color));
+            }
+            draw.shuffle(rng);
+        }
+
+        let draws = draws
+            .iter()
+            .map(|draw| {
+                draw.iter()
+                    .map(|(n, color)| format!("{n} {color}"))
+                    .join(", ")
+            })
+            .join("; ");
+        lines.push(format!("Game {id}: {draws}"));
+
+        if colors
+            .iter()
+            .zip(maxima)
+            .all(|(color, maximum)| maximum <= bag[*color])
+        {
+            part_one += id;
+        }
+        part_two += maxima.iter().product::<u32>();
+    }
+
+    Generated::new(lines).with_answers(Some(part_one as i128), Some(part_two as i128))
+}
@@ -1,15 +1,25 @@
+#[cfg(not(feature = "nightly"))]
+use crate::utils::compat::TryInsert;
+use crate::utils::generate::{is_prime, random_word, Generated, Knobs};
 use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
 use itertools::Itertools;
+use num_bigint::BigUint;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
 use std::collections::{HashMap, VecDeque};
 
 #[derive(PartialEq, Debug, Clone, Copy)]
-enum Pulse {
+pub enum Pulse {
     High,
     Low,
 }
 
 #[derive(Debug, Clone)]
-enum ModuleId {
+pub enum ModuleId {
     Broadcaster,
     Flipflop,
     Conjunction,
@@ -17,16 +27,16 @@ enum ModuleId {
 }
 
 #[derive(Debug, Clone)]
-struct Module {
-    id: ModuleId,
-    name: String,
-    destinations: Vec<String>,
-    incomings: HashMap<String, Pulse>,
-    current_state: Pulse,
+pub struct Module {
+    pub id: ModuleId,
+    pub name: String,
+    pub destinations: Vec<String>,
+    pub incomings: HashMap<String, Pulse>,
+    pub current_state: Pulse,
 }
 
 impl Module {
-    fn on_pulse(&mut self, name_from: &String, pulse: Pulse) -> VecDeque<(String, Pulse, String)> {
+    pub fn on_pulse(&mut self, name_from: &str, pulse: Pulse) -> VecDeque<(String, Pulse, String)> {
         let name = self.name.clone();
         let tt: VecDeque<(String, Pulse, String)> = match &self.id {
             ModuleId::Broadcaster => self
@@ -50,7 +60,7 @@ impl Module {
                 }
             }
             ModuleId::Conjunction => {
-                self.incomings.insert(name_from.clone(), pulse);
+                self.incomings.insert(name_from.to_string(), pulse);
                 let new_state = if self
                     .incomings
                     .iter()
@@ -74,11 +84,13 @@ impl Module {
     }
 }
 
-fn button_press(
+// The low and high pulses sent, the button's included.
+pub fn button_press(
     modules_destination: &mut HashMap<String, Module>,
     multiples_map: &mut HashMap<String, u64>,
     iteration: u64,
-) {
+) -> (u64, u64) {
+    let mut sent = (0, 0);
     let mut events: VecDeque<(String, Pulse, String)> = VecDeque::new();
 
     events.push_front((
@@ -88,6 +100,10 @@ fn button_press(
     ));
 
     while let Some((name_from, pulse, name)) = events.pop_front() {
+        match pulse {
+            Pulse::Low => sent.0 += 1,
+            Pulse::High => sent.1 += 1,
+        }
         if pulse == Pulse::High {
             match name_from.as_str() {
                 // Eyeballed these ones
@@ -103,9 +119,12 @@ fn button_press(
             events.append(tt);
         };
     }
+    sent
 }
 
-fn process_lines(lines: &Vec<String>) -> u64 {
+const PRESSES: u64 = 100000;
+
+pub fn parse_modules(lines: &[String]) -> HashMap<String, Module> {
     let mut modules: HashMap<String, Module> = HashMap::new();
 
     for line in lines {
@@ -164,22 +183,111 @@ fn process_lines(lines: &Vec<String>) -> u64 {
             incomings: output_incoming,
         },
     );
+    modules_destination
+}
+
+// Low pulses times high pulses over 1000 presses, part 1.
+pub fn pulse_product(lines: &[String]) -> u64 {
+    let mut modules_destination = parse_modules(lines);
+    let mut multiples_map: HashMap<String, u64> = HashMap::new();
+    let (low, high) = (1..=1000).fold((0, 0), |(low, high), i| {
+        let sent = button_press(&mut modules_destination, &mut multiples_map, i);
+        (low + sent.0, high + sent.1)
+    });
+    low * high
+}
 
+// The product of the four hub periods.
+pub fn process_lines(lines: &[String]) -> Result<BigUint, String> {
+    let mut modules_destination = parse_modules(lines);
     let mut multiples_map: HashMap<String, u64> = HashMap::new();
 
-    for i in 1..=100000 {
+    for i in 1..=PRESSES {
         button_press(&mut modules_destination, &mut multiples_map, i);
         if multiples_map.len() > 3 {
             break;
         }
     }
+    if multiples_map.len() < 4 {
+        return Err(format!("not every hub fired within {PRESSES} presses"));
+    }
+
+    Ok(multiples_map
+        .values()
+        .map(|multiple| BigUint::from(*multiple))
+        .product())
+}
 
-    multiples_map.values().product()
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 20, input);
+    reporter.solve(["one", "two"], || {
+        let lines = input_to_lines(input);
+        [
+            Ok(pulse_product(&lines).to_string()),
+            process_lines(&lines).map(|product| product.to_string()),
+        ]
+    });
+    reporter.outcomes()
 }
 
 pub fn run() {
-    let input = "./days/day20/input.txt";
-    let lines = input_to_lines(input);
-    let result = process_lines(&lines);
-    println!("\n day19 done with result {result}.");
+    report::print(&solve(&years::input_path(2023, 20)), Format::Table);
+}
+
+// Four binary counters of size bits (5 to 16, what process_lines presses
+// through), each flip-flop chain read by
+// a hub that fires and resets at a prime count. The hubs go through the
+// inverters process_lines watches, then one conjunction, to rx. rx gets its
+// low pulse at the product of the primes. difficulty is not used.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    const INVERTERS: [&str; 4] = ["mk", "fp", "xt", "zc"];
+    let bits = knobs.size.clamp(5, 16) as u32;
+
+    let mut periods: Vec<u64> = vec![];
+    while periods.len() < INVERTERS.len() {
+        let period = rng.gen_range(1 << (bits - 1)..1 << bits);
+        if is_prime(period) && !periods.contains(&period) {
+            periods.push(period);
+        }
+    }
+
+    let mut used: HashSet<String> = INVERTERS.iter().map(|name| name.to_string()).collect();
+    used.extend(["dn", "rx"].map(String::from));
+    let mut name = |rng: &mut StdRng| loop {
+        let name = random_word(rng, b"abcdefghijklmnopqrstuvwxyz", 2);
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut lines = vec![];
+    let mut firsts = vec![];
+    for (period, inverter) in periods.iter().zip(INVERTERS) {
+        let hub = name(rng);
+        let chain = (0..bits).map(|_| name(rng)).collect_vec();
+        firsts.push(chain[0].clone());
+
+        let mut hub_destinations = vec![inverter.to_string()];
+        for (bit, flipflop) in chain.iter().enumerate() {
+            let mut destinations = chain.get(bit + 1).into_iter().cloned().collect_vec();
+            // Set bits report to the hub, the others get reset by it.
+            if period >> bit & 1 == 1 {
+                destinations.push(hub.clone());
+            }
+            if period >> bit & 1 == 0 || bit == 0 {
+                hub_destinations.push(flipflop.clone());
+            }
+            lines.push(format!("%{flipflop} -> {}", destinations.join(", ")));
+        }
+        lines.push(format!("&{hub} -> {}", hub_destinations.join(", ")));
+        lines.push(format!("&{inverter} -> dn"));
+    }
+    lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+    lines.push("&dn -> rx".to_string());
+    lines.shuffle(rng);
+
+    let part_two = periods.iter().try_fold(1_i128, |product, period| {
+        product.checked_mul(*period as i128)
+    });
+    Generated::new(lines).with_answers(None, part_two)
 }
@@ -0,0 +1,291 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::utils::{
+    input_process::input_to_lines,
+    polynomial::Polynomial,
+    render::{Color, Frames, Palette},
+    tui,
+};
+use crate::years;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::fmt;
+
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq)]
+struct Map {
+    terrains: Vec<Vec<Terrain>>,
+    height: usize,
+    width: usize,
+}
+
+#[derive(Clone, Ord, Eq, PartialOrd, PartialEq, Debug, Copy)]
+enum TerrainId {
+    Visited,
+    Empty,
+    Fixed,
+}
+
+#[derive(Debug, Clone, Copy, Ord, Eq, PartialOrd, PartialEq)]
+struct Terrain {
+    id: TerrainId,
+    distance: u64,
+    i: usize,
+    j: usize,
+}
+
+impl Terrain {
+    fn default() -> Self {
+        Self {
+            id: TerrainId::Empty,
+            i: 0,
+            j: 0,
+            distance: u64::MAX,
+        }
+    }
+    fn new(ch: char, i: usize, j: usize) -> Self {
+        let id = match ch {
+            '.' => TerrainId::Empty,
+            '#' => TerrainId::Fixed,
+            'O' => TerrainId::Visited,
+            'S' => TerrainId::Empty,
+            _ => {
+                panic!("Err: unmanaged character : {ch}")
+            }
+        };
+
+        Self {
+            id,
+            i,
+            j,
+            distance: u64::MAX,
+        }
+    }
+}
+
+impl fmt::Display for Terrain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ch = match self.id {
+            TerrainId::Empty => ".".to_string(),
+            TerrainId::Fixed => "#".to_string(),
+            TerrainId::Visited => "O".to_string(),
+        };
+
+        write!(f, "{ch}")
+    }
+}
+
+fn get_candidates(map: &mut Map, starting_terrain: &Terrain) -> Vec<Terrain> {
+    let Terrain { i, j, .. } = *starting_terrain;
+    map.terrains[i].get_mut(j).expect("Err: no terrain").id = TerrainId::Empty;
+
+    let positions = [(-1i32, 0), (1, 0), (0, -1i32), (0, 1)];
+
+    let terrains = positions
+        .iter()
+        .filter_map(|position| {
+            let new_i = (i as i32 + position.0) as usize;
+            let new_j = (j as i32 + position.1) as usize;
+            if (0..(map.height)).contains(&new_i) && (0..(map.width)).contains(&new_j) {
+                return Some((new_i, new_j));
+            }
+            None
+        })
+        .filter_map(|position| {
+            let terrain = map.terrains[position.0]
+                .get_mut(position.1)
+                .expect("Err:  no terrain");
+            if terrain.id != TerrainId::Empty {
+                return None;
+            }
+            terrain.id = TerrainId::Visited;
+            Some(*terrain)
+        })
+        .collect_vec();
+
+    terrains
+}
+
+fn map_lines(map: &Map) -> Vec<String> {
+    map.terrains
+        .iter()
+        .map(|line| line.iter().join(""))
+        .collect_vec()
+}
+
+#[allow(dead_code)]
+fn display_map(map: &Map) {
+    for displayed_line in map_lines(map) {
+        println!("{displayed_line}");
+    }
+    println!();
+}
+
+fn process_lines_part_1(lines: &[String], repeats: usize, frames: &mut Option<Frames>) -> usize {
+    // 64 for part 1. Half a garden to the border, then whole gardens.
+    let side = lines.len();
+    reach(lines, side / 2 + side * repeats, frames)
+}
+
+// Plots reached after `iterations` steps, on a square of gardens just big
+// enough for the walk to never leave it.
+fn reach(lines: &[String], iterations: usize, frames: &mut Option<Frames>) -> usize {
+    let side = lines.len();
+    let repeats = iterations.saturating_sub(side / 2).div_ceil(side);
+    walk(lines, iterations, 1 + repeats * 2, frames)
+}
+
+// Plots reached after `iterations` steps, on times x times gardens.
+fn walk(lines: &[String], iterations: usize, times: usize, frames: &mut Option<Frames>) -> usize {
+    let height = lines.len() * times;
+    let width = lines.first().expect("Err: no first line").len() * times;
+    let mut terrains = vec![vec![Terrain::default(); width]; height];
+
+    let mut repeated_lines: Vec<String> = vec![];
+    for _ in 0..times {
+        repeated_lines.extend_from_slice(lines);
+    }
+
+    for (i, line) in repeated_lines.iter().enumerate().collect_vec().iter() {
+        for (j, ch) in line.repeat(times).chars().enumerate().collect_vec() {
+            let current_terrain = Terrain::new(ch, *i, j);
+            terrains[*i][j] = current_terrain;
+        }
+    }
+
+    let terrains_vec = terrains.iter().flatten().collect_vec();
+    let starting_terrain = **terrains_vec
+        .get(terrains_vec.len() / 2)
+        .expect("Err: no terrain");
+
+    let mut map = Map {
+        height,
+        width,
+        terrains,
+    };
+
+    let mut terrains = vec![starting_terrain];
+
+    for _ in 0..iterations {
+        let next_positions = vec![];
+        let mut reduced: Vec<Terrain> = terrains.iter().fold(next_positions, |mut acc, terrain| {
+            let mut next = get_candidates(&mut map, terrain);
+            acc.append(&mut next);
+            acc
+        });
+        reduced.sort();
+        reduced.dedup();
+        terrains = reduced;
+
+        if let Some(frames) = frames {
+            frames
+                .record(&map_lines(&map))
+                .expect("Err: could not write frame");
+        }
+    }
+
+    // display_map(&map);
+
+    terrains.len()
+}
+
+// Reachable plots after offset + side * n steps grow quadratically in n, for
+// any offset once the walk is past the first border. Fitted on the first
+// samples past it, walked directly when that is not shorter.
+pub fn garden_steps(lines: &[String], steps: usize) -> BigInt {
+    let side = lines.len();
+    if steps < side / 2 + side * 4 {
+        return BigInt::from(reach(lines, steps, &mut None));
+    }
+    let offset = side / 2 + (steps - side / 2) % side;
+
+    let samples = [0, 1, 2, 3];
+    let datum_ints: Vec<i128> = samples
+        .iter()
+        .map(|n| reach(lines, offset + side * n, &mut None) as i128)
+        .collect_vec();
+
+    Polynomial::fit(&datum_ints).evaluate(((steps - offset) / side) as i128)
+}
+
+// Within the one garden.
+fn part_1(lines: &[String]) -> usize {
+    walk(lines, 64, 1, &mut None)
+}
+
+fn part_2(lines: &[String]) -> BigInt {
+    garden_steps(lines, 26501365)
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 21, input);
+    reporter.solve(["one", "two"], || {
+        let lines = input_to_lines(input);
+        [
+            Ok(part_1(&lines).to_string()),
+            Ok(part_2(&lines).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 21)), Format::Table);
+}
+
+fn palette() -> Palette {
+    Palette::new(&[('O', [120, 220, 90]), ('#', [70, 60, 50])], [25, 40, 25])
+}
+
+// The BFS frontier, one frame per step, on the 5x5 tiled garden.
+pub(crate) fn animate(input: &str, directory: &str, colors: &[Color], scale: Option<usize>) {
+    let lines = input_to_lines(input);
+    let palette = palette().with(colors);
+    let frames =
+        Frames::new(directory, palette, scale.unwrap_or(2), 1).expect("Err: no frame directory");
+    process_lines_part_1(&lines, 2, &mut Some(frames));
+    println!("\n day21 frames written to {directory}.");
+}
+
+// Part 1 garden, the frontier drawn by Terrain's Display.
+pub(crate) fn visualize(input: &str) {
+    let lines = input_to_lines(input);
+    let mut frames = Some(Frames::in_memory(palette(), 1));
+    let result = process_lines_part_1(&lines, 0, &mut frames);
+    let frames = frames.expect("Err: no frames");
+    tui::browse("day21 garden", &frames, &|grid| {
+        grid.iter()
+            .map(|line| line.matches('O').count())
+            .sum::<usize>()
+            .to_string()
+    })
+    .expect("Err: terminal");
+    println!("\n day21 done, with result {result}.");
+}
+
+// A (2 size + 1)-wide square garden, S in the middle, its row, its column
+// and the border kept free. More rocks as difficulty grows.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let half = knobs.size.max(1);
+    let side = 2 * half + 1;
+    let rocks = (0.05 * (1 + knobs.difficulty) as f64).min(0.4);
+    let lines = (0..side)
+        .map(|i| {
+            (0..side)
+                .map(|j| {
+                    let free = i == half || j == half || i == 0 || j == 0;
+                    let free = free || i == side - 1 || j == side - 1;
+                    if i == half && j == half {
+                        'S'
+                    } else if !free && rng.gen_bool(rocks) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    Generated::new(lines)
+}
@@ -0,0 +1,221 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use petgraph::algo::dominators::simple_fast;
+use petgraph::graph::{DiGraph, NodeIndex};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+
+pub type Point = (usize, usize, usize);
+pub type Span = (usize, usize);
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Brick {
+    pub x: Span,
+    pub y: Span,
+    pub z: Span,
+    pub id: usize,
+}
+
+impl Brick {
+    pub fn new(start: Point, end: Point, id: usize) -> Self {
+        Brick {
+            x: (start.0.min(end.0), start.0.max(end.0)),
+            y: (start.1.min(end.1), start.1.max(end.1)),
+            z: (start.2.min(end.2), start.2.max(end.2)),
+            id,
+        }
+    }
+
+    pub fn footprint(&self) -> impl Iterator<Item = (usize, usize)> {
+        (self.x.0..=self.x.1).cartesian_product(self.y.0..=self.y.1)
+    }
+}
+
+// Node 0 is the ground, nodes has the node of each brick id.
+// Edges go from a brick to the bricks it holds up.
+pub struct Stack {
+    pub bricks: Vec<Brick>,
+    pub graph: DiGraph<usize, ()>,
+    pub nodes: HashMap<usize, NodeIndex>,
+}
+
+pub fn str_to_point(coordinates: &str) -> Point {
+    coordinates
+        .split(',')
+        .map(|ch| ch.parse::<usize>().expect("Err: nan"))
+        .collect_tuple()
+        .expect("Err: not a tuple")
+}
+
+// Lowest first, each brick drops onto the height map of what already landed.
+pub fn add_gravity(mut bricks: Vec<Brick>) -> Stack {
+    bricks.sort_by_key(|brick| brick.z.0);
+
+    let mut graph = DiGraph::new();
+    let ground = graph.add_node(usize::MAX);
+    let nodes: HashMap<usize, NodeIndex> = bricks
+        .iter()
+        .map(|brick| (brick.id, graph.add_node(brick.id)))
+        .collect();
+
+    // (x, y) -> (top z, brick id)
+    let mut heights: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+
+    for brick in bricks.iter_mut() {
+        let floor = brick
+            .footprint()
+            .filter_map(|cell| heights.get(&cell))
+            .map(|(height, _)| *height)
+            .max()
+            .unwrap_or(0);
+
+        let supports = brick
+            .footprint()
+            .filter_map(|cell| heights.get(&cell))
+            .filter(|(height, _)| *height == floor && floor > 0)
+            .map(|(_, id)| *id)
+            .unique()
+            .collect_vec();
+
+        let fall = brick.z.0 - (floor + 1);
+        brick.z = (brick.z.0 - fall, brick.z.1 - fall);
+
+        let node = nodes[&brick.id];
+        if supports.is_empty() {
+            graph.add_edge(ground, node, ());
+        }
+        for support in supports {
+            graph.add_edge(nodes[&support], node, ());
+        }
+
+        for cell in brick.footprint() {
+            heights.insert(cell, (brick.z.1, brick.id));
+        }
+    }
+
+    Stack {
+        bricks,
+        graph,
+        nodes,
+    }
+}
+
+// A brick falls with b exactly when b dominates it from the ground,
+// so the chain reaction of b is its dominator subtree. By brick id.
+pub fn get_chains(stack: &Stack) -> Vec<usize> {
+    let ground = NodeIndex::new(0);
+    let dominators = simple_fast(&stack.graph, ground);
+
+    let mut subtree = vec![1_usize; stack.graph.node_count()];
+    // Settled order is a topological order, walk it top down.
+    for brick in stack.bricks.iter().rev() {
+        let node = stack.nodes[&brick.id];
+        let parent = dominators
+            .immediate_dominator(node)
+            .expect("Err: brick not reachable from ground");
+        subtree[parent.index()] += subtree[node.index()];
+    }
+
+    stack
+        .nodes
+        .iter()
+        .sorted()
+        .map(|(_, node)| subtree[node.index()] - 1)
+        .collect()
+}
+
+pub fn find_disintegrable_part_1(chains: &[usize]) -> usize {
+    chains.iter().filter(|chain| **chain == 0).count()
+}
+
+pub fn find_chain_part_2(chains: &[usize]) -> usize {
+    chains.iter().sum()
+}
+
+pub fn process_lines_part(lines: &[String]) -> (usize, usize) {
+    let bricks = lines
+        .iter()
+        .enumerate()
+        .map(|(id, line)| {
+            let (starts, ends) = line.split_once('~').expect("Err: no tilde");
+            Brick::new(str_to_point(starts), str_to_point(ends), id)
+        })
+        .collect_vec();
+
+    let stack = add_gravity(bricks);
+    let chains = get_chains(&stack);
+
+    (
+        find_disintegrable_part_1(&chains),
+        find_chain_part_2(&chains),
+    )
+}
+
+// This one was fun.
+// Bricks are now a support graph, both parts read its dominator tree.
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 22, input);
+    reporter.solve(["one", "two"], || {
+        let lines = input_to_lines(input);
+        let (part_1, part_2) = process_lines_part(&lines);
+        [Ok(part_1.to_string()), Ok(part_2.to_string())]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 22)), Format::Table);
+}
+
+// size piles on 3x3 footprints, far enough apart not to touch, each of 3 to
+// 3 + 3 * difficulty layers. A layer is either a single brick along y in the
+// middle, or a pair of bricks along x on both sides. Layers float apart by up
+// to difficulty, gravity packs them back in the same order.
+//
+// That fixes what falls: removing a single takes everything above with it,
+// removing one of a pair takes its twin in every pair right above.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let per_row = (knobs.size.max(1) as f64).sqrt().ceil() as usize;
+    let mut lines = vec![];
+    let (mut part_one, mut part_two) = (0, 0);
+
+    for pile in 0..knobs.size.max(1) {
+        let (x, y) = (4 * (pile % per_row), 4 * (pile / per_row));
+        let pairs = (0..rng.gen_range(3..=3 + 3 * knobs.difficulty))
+            .map(|_| rng.gen_bool(0.5))
+            .collect_vec();
+
+        let mut z = 1 + rng.gen_range(0..=knobs.difficulty);
+        for (layer, pair) in pairs.iter().enumerate() {
+            let above = &pairs[layer + 1..];
+            match pair {
+                true => {
+                    for side in [y, y + 2] {
+                        lines.push(format!("{x},{side},{z}~{},{side},{z}", x + 2));
+                    }
+                    let falls = above.iter().take_while(|pair| **pair).count();
+                    part_one += 2 * usize::from(falls == 0);
+                    part_two += 2 * falls;
+                }
+                false => {
+                    lines.push(format!("{},{y},{z}~{},{},{z}", x + 1, x + 1, y + 2));
+                    let falls = above
+                        .iter()
+                        .map(|pair| 1 + usize::from(*pair))
+                        .sum::<usize>();
+                    part_one += usize::from(falls == 0);
+                    part_two += falls;
+                }
+            }
+            z += 1 + rng.gen_range(0..=knobs.difficulty);
+        }
+    }
+    lines.shuffle(rng);
+
+    Generated::new(lines).with_answers(Some(part_one as i128), Some(part_two as i128))
+}
@@ -0,0 +1,346 @@
+#[cfg(not(feature = "nightly"))]
+use crate::utils::compat::MapWindows;
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use petgraph::algo::all_simple_paths;
+use petgraph::graphmap::GraphMap;
+use petgraph::{Directed, EdgeType, Undirected};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::VecDeque;
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Clone, Hash, Copy)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Clone, Hash, Copy)]
+pub enum PlaceId {
+    Path,
+    Forest,
+    Slope,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Copy)]
+pub struct Place {
+    pub i: usize,
+    pub j: usize,
+    pub id: PlaceId,
+    pub direction: Direction,
+}
+
+impl Place {
+    pub fn get_key(&self) -> (usize, usize) {
+        (self.i, self.j)
+    }
+}
+
+fn get_at_direction(
+    current_place: &Place,
+    direction: &Direction,
+    map: &mut Map,
+    cost: usize,
+) -> Option<(Place, Direction, usize)> {
+    let Place { mut i, mut j, .. } = current_place;
+
+    // Downhill only, part 1.
+    if map.slippery && current_place.id == PlaceId::Slope && current_place.direction != *direction {
+        return None;
+    }
+
+    match direction {
+        Direction::North => {
+            i = i.checked_sub(1)?;
+        }
+        Direction::South => {
+            i += 1;
+            if i >= map.height {
+                return None;
+            }
+        }
+        Direction::East => {
+            j += 1;
+            if j >= map.width {
+                return None;
+            }
+        }
+        Direction::West => {
+            j = j.checked_sub(1)?;
+        }
+    }
+
+    let place = map.places[i][j];
+
+    if let PlaceId::Forest = place.id {
+        return None;
+    }
+
+    Some((place, *direction, cost + 1))
+}
+
+fn get_opposite(lhs: &Direction) -> Direction {
+    match lhs {
+        Direction::North => Direction::South,
+        Direction::East => Direction::West,
+        Direction::West => Direction::East,
+        Direction::South => Direction::North,
+    }
+}
+
+fn get_successors(
+    place_from: &Place,
+    map: &mut Map,
+    direction_from: Direction,
+    cost: usize,
+) -> Vec<(Place, Direction, usize)> {
+    [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ]
+    .iter()
+    .filter(|direction| get_opposite(direction) != direction_from)
+    .filter_map(|direction| get_at_direction(place_from, direction, map, cost))
+    .collect_vec()
+}
+
+impl Place {
+    pub fn new(i: usize, j: usize, ch: char) -> Self {
+        let id = match ch {
+            '#' => PlaceId::Forest,
+            '.' => PlaceId::Path,
+            '<' | '>' | 'v' => PlaceId::Slope,
+            _ => panic!("Err: wrong char {ch}"),
+        };
+
+        let direction = match ch {
+            '<' => Direction::West,
+            '>' => Direction::East,
+            'v' => Direction::South,
+            _ => Direction::North,
+        };
+
+        Place {
+            i,
+            j,
+            id,
+            direction,
+        }
+    }
+}
+
+pub struct Map {
+    pub places: Vec<Vec<Place>>,
+    pub height: usize,
+    pub width: usize,
+    pub slippery: bool,
+}
+
+// More than two ways out, slopes or not.
+fn is_junction(place: &Place, map: &Map) -> bool {
+    let Place { i, j, .. } = *place;
+    [
+        (i.wrapping_sub(1), j),
+        (i + 1, j),
+        (i, j.wrapping_sub(1)),
+        (i, j + 1),
+    ]
+    .iter()
+    .filter(|(i, j)| *i < map.height && *j < map.width)
+    .filter(|(i, j)| map.places[*i][*j].id != PlaceId::Forest)
+    .count()
+        > 2
+}
+
+// The one path cell of the top or bottom row.
+fn opening(row: &[Place]) -> Place {
+    *row.iter()
+        .find(|place| place.id != PlaceId::Forest)
+        .expect("Err: no opening")
+}
+
+// Directed edges when slippery, a corridor with a slope goes one way.
+pub fn walk_reduce<Ty: EdgeType>(map: &mut Map) -> usize {
+    let places = map.places.clone();
+    let start = opening(places.first().expect("Err: no first row"));
+    let end = opening(places.last().expect("Err: no last row"));
+
+    let mut graph: GraphMap<(usize, usize), usize, Ty> = GraphMap::new();
+    graph.add_node(start.get_key());
+    graph.add_node(end.get_key());
+
+    let mut successors: Vec<(Place, Place, Direction, usize)> =
+        vec![(start, start, Direction::South, 0)];
+
+    // Build faster graph
+    while let Some((current_place, mut edge_start, direction_from, cost)) = successors.pop() {
+        let current_place_key = current_place.get_key();
+
+        let current_successors = get_successors(&current_place, map, direction_from, cost);
+        let mut reset_costs = false;
+        if is_junction(&current_place, map) || current_place.get_key() == end.get_key() {
+            if graph.contains_edge(edge_start.get_key(), current_place_key) {
+                continue;
+            };
+            graph.add_edge(edge_start.get_key(), current_place_key, cost);
+            edge_start = current_place;
+            reset_costs = true;
+        }
+
+        for (current_successor, direction_from, cost) in current_successors {
+            let cost = if reset_costs { 1 } else { cost };
+            successors.push((current_successor, edge_start, direction_from, cost));
+        }
+    }
+
+    // Use faster graph
+    let ways = all_simple_paths::<Vec<_>, _>(&graph, start.get_key(), end.get_key(), 0, None)
+        .collect::<Vec<_>>();
+
+    ways.iter()
+        .map(|path| {
+            path.iter()
+                .map_windows(|&[a, b]| graph.edge_weight(*a, *b).expect("Err: no edge"))
+                .sum::<usize>()
+        })
+        .max()
+        .expect("Err: no max")
+}
+
+pub fn process_input(inputs: Vec<String>, slippery: bool) -> usize {
+    let places = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, ch)| Place::new(i, j, ch))
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let width = inputs.first().expect("Err: no input").len();
+    let height = inputs.len();
+
+    let mut map = Map {
+        places,
+        height,
+        width,
+        slippery,
+    };
+
+    if slippery {
+        walk_reduce::<Directed>(&mut map)
+    } else {
+        walk_reduce::<Undirected>(&mut map)
+    }
+}
+
+// .. lib again. Because it is a wheel I should reinvent again.
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 23, input);
+    reporter.solve(["one", "two"], || {
+        let data = input_to_lines(input);
+        [
+            Ok(process_input(data.clone(), true).to_string()),
+            Ok(process_input(data, false).to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 23)), Format::Table);
+}
+
+// A (2 size + 1)-wide maze dug from the top left to the bottom right. At
+// difficulty 0 it is a tree, its only path is the answer to both parts.
+// From difficulty 1 on, size * difficulty extra walls come down and some
+// passages get slopes.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let cells = knobs.size.max(2);
+    let side = 2 * cells + 1;
+    let mut grid = vec![vec!['#'; side]; side];
+
+    // Depth first digging, from cell (0, 0).
+    let mut visited = vec![vec![false; cells]; cells];
+    let mut stack = vec![(0, 0)];
+    visited[0][0] = true;
+    grid[1][1] = '.';
+    while let Some(&(i, j)) = stack.last() {
+        let next = [(0, 1), (1, 0), (0, -1), (-1, 0)]
+            .iter()
+            .map(|(di, dj)| (i as i64 + di, j as i64 + dj))
+            .filter(|(ni, nj)| (0..cells as i64).contains(ni) && (0..cells as i64).contains(nj))
+            .map(|(ni, nj)| (ni as usize, nj as usize))
+            .filter(|(ni, nj)| !visited[*ni][*nj])
+            .collect_vec();
+        let Some(&(ni, nj)) = next.choose(rng) else {
+            stack.pop();
+            continue;
+        };
+
+        visited[ni][nj] = true;
+        grid[2 * ni + 1][2 * nj + 1] = '.';
+        let slope = match (ni > i, nj > j) {
+            _ if knobs.difficulty == 0 || rng.gen_bool(0.7) => '.',
+            (true, _) => 'v',
+            (_, true) => '>',
+            _ => '.',
+        };
+        grid[i + ni + 1][j + nj + 1] = slope;
+        stack.push((ni, nj));
+    }
+
+    for _ in 0..cells * knobs.difficulty {
+        let (i, j) = (rng.gen_range(1..side - 1), rng.gen_range(1..side - 1));
+        // Walls between two cells only.
+        if (i + j) % 2 == 1 {
+            grid[i][j] = '.';
+        }
+    }
+    grid[0][1] = '.';
+    grid[side - 1][side - 2] = '.';
+
+    let lines = grid
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect_vec();
+    match knobs.difficulty {
+        0 => {
+            let steps = path_length(&grid) as i128;
+            Generated::new(lines).with_answers(Some(steps), Some(steps))
+        }
+        _ => Generated::new(lines),
+    }
+}
+
+// Breadth first, from the top opening to the bottom one.
+fn path_length(grid: &[Vec<char>]) -> usize {
+    let side = grid.len();
+    let mut distances = vec![vec![usize::MAX; side]; side];
+    let mut queue = VecDeque::from([(0_usize, 1_usize)]);
+    distances[0][1] = 0;
+    while let Some((i, j)) = queue.pop_front() {
+        for (ni, nj) in [
+            (i.wrapping_sub(1), j),
+            (i + 1, j),
+            (i, j.wrapping_sub(1)),
+            (i, j + 1),
+        ] {
+            if ni < side && nj < side && grid[ni][nj] != '#' && distances[ni][nj] == usize::MAX {
+                distances[ni][nj] = distances[i][j] + 1;
+                queue.push_back((ni, nj));
+            }
+        }
+    }
+    distances[side - 1][side - 2]
+}
@@ -0,0 +1,638 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Add;
+use std::ops::Sub;
+
+use crate::utils::input_process::input_to_lines;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    px: i128,
+    py: i128,
+    pz: i128,
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point {
+            px: self.px - other.px,
+            py: self.py - other.py,
+            pz: self.pz - other.pz,
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point {
+            px: self.px + other.px,
+            py: self.py + other.py,
+            pz: self.pz + other.pz,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Vector {
+    vx: i128,
+    vy: i128,
+    vz: i128,
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector {
+            vx: self.vx - other.vx,
+            vy: self.vy - other.vy,
+            vz: self.vz - other.vz,
+        }
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector {
+            vx: self.vx + other.vx,
+            vy: self.vy + other.vy,
+            vz: self.vz + other.vz,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stone {
+    point: Point,
+    vector: Vector,
+}
+
+impl Sub for Stone {
+    type Output = Stone;
+
+    fn sub(self, other: Stone) -> Stone {
+        Stone {
+            point: self.point - other.point,
+            vector: self.vector - other.vector,
+        }
+    }
+}
+
+impl fmt::Display for Stone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:}, {:}, {:} @ {:}, {:}, {:}",
+            self.point.px,
+            self.point.py,
+            self.point.pz,
+            self.vector.vx,
+            self.vector.vy,
+            self.vector.vz
+        )
+    }
+}
+
+pub fn get_places(inputs: &[String]) -> Vec<Stone> {
+    inputs
+        .iter()
+        .map(|line| {
+            let (p, v) = line.split_once('@').expect("Err: no @");
+            let [px, py, pz] = p
+                .trim()
+                .split(", ")
+                .map(|item| item.parse::<i128>().expect("Err: point not an i128"))
+                .collect_vec()[0..=2]
+            else {
+                panic!("Err: places");
+            };
+
+            let [vx, vy, vz] = v
+                .split(", ")
+                .map(|item| item.trim().parse::<i128>().expect("Err: place not an i128"))
+                .collect_vec()[0..=2]
+            else {
+                panic!("Err: places");
+            };
+
+            Stone {
+                point: Point { px, py, pz },
+                vector: Vector { vx, vy, vz },
+            }
+        })
+        .collect_vec()
+}
+
+// Part 1, XY plane only.
+
+#[derive(Debug, Clone, Copy)]
+pub struct Area {
+    low: i128,
+    high: i128,
+}
+
+pub const TEST_AREA: Area = Area {
+    low: 200000000000000,
+    high: 400000000000000,
+};
+
+impl Area {
+    // Both bounds included, on x and y.
+    pub fn new(low: i128, high: i128) -> Area {
+        Area { low, high }
+    }
+
+    fn contains(&self, value: &BigRational) -> bool {
+        integer(self.low) <= *value && *value <= integer(self.high)
+    }
+}
+
+// Exact, however far the stones start: i128 products of the positions
+// already go past 2^100.
+fn integer(value: i128) -> BigRational {
+    BigRational::from_integer(BigInt::from(value))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing {
+    // Coincident paths included.
+    Parallel,
+    // At least one of the stones was there before time 0.
+    Past,
+    Inside,
+    Outside,
+}
+
+pub fn classify(lhs: &Stone, rhs: &Stone, area: &Area) -> Crossing {
+    let Stone {
+        point: p1,
+        vector: v1,
+    } = lhs;
+    let Stone {
+        point: p2,
+        vector: v2,
+    } = rhs;
+
+    // p1 + t v1 = p2 + s v2, by Cramer.
+    let det = integer(v1.vx) * integer(v2.vy) - integer(v1.vy) * integer(v2.vx);
+    if det.is_zero() {
+        return Crossing::Parallel;
+    }
+    let (dx, dy) = (
+        integer(p2.px) - integer(p1.px),
+        integer(p2.py) - integer(p1.py),
+    );
+    let t = (&dx * integer(v2.vy) - &dy * integer(v2.vx)) / &det;
+    let s = (&dx * integer(v1.vy) - &dy * integer(v1.vx)) / &det;
+    if t.is_negative() || s.is_negative() {
+        return Crossing::Past;
+    }
+
+    let x = integer(p1.px) + &t * integer(v1.vx);
+    let y = integer(p1.py) + &t * integer(v1.vy);
+    if area.contains(&x) && area.contains(&y) {
+        Crossing::Inside
+    } else {
+        Crossing::Outside
+    }
+}
+
+// X range covered by the future path while inside the area.
+fn clip_to_area(stone: &Stone, area: &Area) -> Option<(BigRational, BigRational)> {
+    let mut enter = BigRational::zero();
+    let mut exit: Option<BigRational> = None;
+    for (position, speed) in [
+        (stone.point.px, stone.vector.vx),
+        (stone.point.py, stone.vector.vy),
+    ] {
+        if speed == 0 {
+            if position < area.low || area.high < position {
+                return None;
+            }
+            continue;
+        }
+        let low = (integer(area.low) - integer(position)) / integer(speed);
+        let high = (integer(area.high) - integer(position)) / integer(speed);
+        let (first, last) = match low <= high {
+            true => (low, high),
+            false => (high, low),
+        };
+        enter = enter.max(first);
+        exit = Some(exit.map_or(last.clone(), |exit| exit.min(last)));
+    }
+
+    // A still stone stays where it is.
+    let exit = exit.unwrap_or_else(|| enter.clone());
+    if enter > exit {
+        return None;
+    }
+
+    let x_at = |t: &BigRational| integer(stone.point.px) + t * integer(stone.vector.vx);
+    let (x_enter, x_exit) = (x_at(&enter), x_at(&exit));
+    match x_enter <= x_exit {
+        true => Some((x_enter, x_exit)),
+        false => Some((x_exit, x_enter)),
+    }
+}
+
+// Stones sorted by where their clipped path starts in X, a pair is only
+// classified when both X ranges overlap. That skips stones whose paths
+// never meet inside the area, every pair is still looked at when the
+// ranges all overlap.
+pub fn count_inside(stones: &[Stone], area: &Area) -> usize {
+    let clipped = stones
+        .iter()
+        .filter_map(|stone| clip_to_area(stone, area).map(|range| (range, stone)))
+        .sorted_by(|lhs, rhs| lhs.0 .0.cmp(&rhs.0 .0))
+        .collect_vec();
+
+    let mut active: Vec<(BigRational, &Stone)> = vec![];
+    let mut count = 0;
+    for ((start, end), stone) in clipped {
+        active.retain(|(active_end, _)| *active_end >= start);
+        count += active
+            .iter()
+            .filter(|(_, other)| classify(stone, other, area) == Crossing::Inside)
+            .count();
+        active.push((end, stone));
+    }
+    count
+}
+
+// Part 2, exact arithmetic all along.
+
+#[derive(Debug, Clone)]
+pub struct Throw {
+    pub(crate) position: [BigRational; 3],
+    pub(crate) velocity: [BigRational; 3],
+    // When the rock meets each hailstone, in input order.
+    times: Vec<BigRational>,
+}
+
+impl Throw {
+    pub fn position(&self) -> &[BigRational; 3] {
+        &self.position
+    }
+
+    pub fn velocity(&self) -> &[BigRational; 3] {
+        &self.velocity
+    }
+
+    pub fn times(&self) -> &[BigRational] {
+        &self.times
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThrowError {
+    TooFewStones,
+    // The rows leave more than a line of rocks, or every rock of a line fits.
+    Degenerate,
+    // No rock meets every stone.
+    NoRock,
+    // The rock of the linear rows never meets this stone.
+    Misses(usize),
+}
+
+fn to_big(stone: &Stone) -> ([BigRational; 3], [BigRational; 3]) {
+    (
+        [
+            integer(stone.point.px),
+            integer(stone.point.py),
+            integer(stone.point.pz),
+        ],
+        [
+            integer(stone.vector.vx),
+            integer(stone.vector.vy),
+            integer(stone.vector.vz),
+        ],
+    )
+}
+
+fn cross(lhs: &[BigRational; 3], rhs: &[BigRational; 3]) -> [BigRational; 3] {
+    [
+        &lhs[1] * &rhs[2] - &lhs[2] * &rhs[1],
+        &lhs[2] * &rhs[0] - &lhs[0] * &rhs[2],
+        &lhs[0] * &rhs[1] - &lhs[1] * &rhs[0],
+    ]
+}
+
+// Rock P, V and stone p, v collide when (P - p) x (V - v) = 0. The P x V term
+// is the same for every stone, so two stones i, j give three linear rows:
+// P x (v_i - v_j) + (p_i - p_j) x V = p_i x v_i - p_j x v_j
+fn pair_rows(lhs: &Stone, rhs: &Stone) -> Vec<Vec<BigRational>> {
+    let (p_i, v_i) = to_big(lhs);
+    let (p_j, v_j) = to_big(rhs);
+    let a: Vec<BigRational> = (0..3).map(|c| &v_i[c] - &v_j[c]).collect();
+    let b: Vec<BigRational> = (0..3).map(|c| &p_i[c] - &p_j[c]).collect();
+    let c_i = cross(&p_i, &v_i);
+    let c_j = cross(&p_j, &v_j);
+    let zero = BigRational::zero;
+
+    // Columns: Px, Py, Pz, Vx, Vy, Vz, right hand side.
+    vec![
+        vec![
+            zero(),
+            a[2].clone(),
+            -&a[1],
+            zero(),
+            -&b[2],
+            b[1].clone(),
+            &c_i[0] - &c_j[0],
+        ],
+        vec![
+            -&a[2],
+            zero(),
+            a[0].clone(),
+            b[2].clone(),
+            zero(),
+            -&b[0],
+            &c_i[1] - &c_j[1],
+        ],
+        vec![
+            a[1].clone(),
+            -&a[0],
+            zero(),
+            -&b[1],
+            b[0].clone(),
+            zero(),
+            &c_i[2] - &c_j[2],
+        ],
+    ]
+}
+
+// One solution and a basis of the homogeneous ones.
+type Solutions = (Vec<BigRational>, Vec<Vec<BigRational>>);
+
+// Gauss-Jordan on an augmented system, None when inconsistent.
+fn gauss_jordan(mut rows: Vec<Vec<BigRational>>, unknowns: usize) -> Option<Solutions> {
+    let mut pivots = vec![];
+    for column in 0..unknowns {
+        let at = pivots.len();
+        let Some(pivot) = (at..rows.len()).find(|row| !rows[*row][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(at, pivot);
+
+        let pivot_row = rows[at]
+            .iter()
+            .map(|cell| cell / &rows[at][column])
+            .collect_vec();
+        for row in rows.iter_mut() {
+            if row[column].is_zero() {
+                continue;
+            }
+            let factor = row[column].clone();
+            for (cell, pivot_cell) in row.iter_mut().zip(pivot_row.iter()) {
+                *cell -= &factor * pivot_cell;
+            }
+        }
+        rows[at] = pivot_row;
+        pivots.push(column);
+    }
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !row[unknowns].is_zero())
+    {
+        return None;
+    }
+
+    let mut solution = vec![BigRational::zero(); unknowns];
+    for (row, column) in pivots.iter().enumerate() {
+        solution[*column] = rows[row][unknowns].clone();
+    }
+    let free = (0..unknowns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut direction = vec![BigRational::zero(); unknowns];
+            direction[free] = BigRational::one();
+            for (row, column) in pivots.iter().enumerate() {
+                direction[*column] = -&rows[row][free];
+            }
+            direction
+        })
+        .collect();
+    Some((solution, free))
+}
+
+// Rational roots of a s^2 + b s + c, not all zero.
+fn rational_roots(a: &BigRational, b: &BigRational, c: &BigRational) -> Vec<BigRational> {
+    if a.is_zero() {
+        return match b.is_zero() {
+            true => vec![],
+            false => vec![-c / b],
+        };
+    }
+    let discriminant = b * b - BigRational::from_integer(BigInt::from(4)) * a * c;
+    if discriminant.is_negative() {
+        return vec![];
+    }
+    let (numer, denom) = (discriminant.numer().sqrt(), discriminant.denom().sqrt());
+    if &numer * &numer != *discriminant.numer() || &denom * &denom != *discriminant.denom() {
+        return vec![];
+    }
+    let root = BigRational::new(numer, denom);
+    let two_a = a * BigRational::from_integer(BigInt::from(2));
+    vec![(-b + &root) / &two_a, (-b - root) / two_a]
+}
+
+// The linear rows drop the P x V term, with a line of solutions left the
+// collision (P - p) x (V - v) = 0 of one stone is a quadratic along it.
+fn candidates(
+    stones: &[Stone],
+    (solution, free): Solutions,
+) -> Result<Vec<Vec<BigRational>>, ThrowError> {
+    let direction = match free.as_slice() {
+        [] => return Ok(vec![solution]),
+        [direction] => direction,
+        _ => return Err(ThrowError::Degenerate),
+    };
+    let along = |s: &BigRational| {
+        solution
+            .iter()
+            .zip(direction)
+            .map(|(x, d)| x + s * d)
+            .collect_vec()
+    };
+    let collision = |s: &BigRational, stone: &Stone| {
+        let rock = along(s);
+        let (point, vector) = to_big(stone);
+        let gap = [0, 1, 2].map(|c| &rock[c] - &point[c]);
+        let closing = [0, 1, 2].map(|c| &rock[c + 3] - &vector[c]);
+        cross(&gap, &closing)
+    };
+
+    // Quadratic from its values at 0, 1 and -1.
+    let one = BigRational::one();
+    let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+    for stone in stones {
+        let (at_zero, at_one, at_minus) = (
+            collision(&BigRational::zero(), stone),
+            collision(&one, stone),
+            collision(&-&one, stone),
+        );
+        for c in 0..3 {
+            let a = (&at_one[c] + &at_minus[c]) * &half - &at_zero[c];
+            let b = (&at_one[c] - &at_minus[c]) * &half;
+            if a.is_zero() && b.is_zero() && at_zero[c].is_zero() {
+                continue;
+            }
+            return Ok(rational_roots(&a, &b, &at_zero[c])
+                .iter()
+                .map(along)
+                .collect());
+        }
+    }
+    Err(ThrowError::Degenerate)
+}
+
+// Time at which the rock meets the stone, if it ever does.
+fn meeting_time(
+    position: &[BigRational; 3],
+    velocity: &[BigRational; 3],
+    stone: &Stone,
+) -> Option<BigRational> {
+    let (point, vector) = to_big(stone);
+    let mut time: Option<BigRational> = None;
+    for c in 0..3 {
+        let gap = &point[c] - &position[c];
+        let closing = &velocity[c] - &vector[c];
+        if closing.is_zero() {
+            if !gap.is_zero() {
+                return None;
+            }
+            continue;
+        }
+        let t = gap / closing;
+        match &time {
+            Some(time) if *time != t => return None,
+            _ => time = Some(t),
+        }
+    }
+
+    // Same trajectory all along: they touch from the start.
+    let time = time.unwrap_or_else(BigRational::zero);
+    (!time.is_negative()).then_some(time)
+}
+
+pub fn throw_rock(stones: &[Stone]) -> Result<Throw, ThrowError> {
+    if stones.len() < 3 {
+        return Err(ThrowError::TooFewStones);
+    }
+
+    // One stone more until the rows pin the rock down, three usually do.
+    let mut count = 3;
+    let solutions = loop {
+        let rows = stones[1..count]
+            .iter()
+            .flat_map(|stone| pair_rows(&stones[0], stone))
+            .collect_vec();
+        let solutions = gauss_jordan(rows, 6).ok_or(ThrowError::NoRock)?;
+        if solutions.1.is_empty() || count == stones.len() {
+            break solutions;
+        }
+        count += 1;
+    };
+
+    let mut missed = ThrowError::NoRock;
+    for unknowns in candidates(stones, solutions)? {
+        let position = [
+            unknowns[0].clone(),
+            unknowns[1].clone(),
+            unknowns[2].clone(),
+        ];
+        let velocity = [
+            unknowns[3].clone(),
+            unknowns[4].clone(),
+            unknowns[5].clone(),
+        ];
+
+        let times = stones
+            .iter()
+            .enumerate()
+            .map(|(i, stone)| {
+                meeting_time(&position, &velocity, stone).ok_or(ThrowError::Misses(i))
+            })
+            .collect::<Result<Vec<_>, _>>();
+        match times {
+            Ok(times) => {
+                return Ok(Throw {
+                    position,
+                    velocity,
+                    times,
+                })
+            }
+            Err(error) if missed == ThrowError::NoRock => missed = error,
+            Err(_) => (),
+        }
+    }
+    Err(missed)
+}
+
+// Sum of the rock's coordinates.
+fn rock_sum(lines: &[String]) -> Result<BigRational, ThrowError> {
+    let throw = throw_rock(&get_places(lines))?;
+    Ok(throw.position.iter().sum())
+}
+
+// By hook or by crook
+// Should unify Point and Vector types.
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 24, input);
+    reporter.solve(["one"], || {
+        let data = input_to_lines(input);
+        [Ok(count_inside(&get_places(&data), &TEST_AREA).to_string())]
+    });
+    reporter.solve(["two"], || {
+        let data = input_to_lines(input);
+        [rock_sum(&data)
+            .map(|sum| sum.to_string())
+            .map_err(|error| format!("no rock: {error:?}"))]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 24)), Format::Table);
+}
+
+// size hailstones, each hit by the same rock at a different time. Positions
+// are around 10^(12 + difficulty), the puzzle's 2 * 10^14 at difficulty 2.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let scale = 10_i128.pow(12 + knobs.difficulty.min(12) as u32);
+    let rock = [0; 3].map(|_| rng.gen_range(scale..5 * scale));
+    let speed = [0; 3].map(|_| rng.gen_range(-300..=300));
+
+    let mut times = HashSet::new();
+    let mut lines = vec![];
+    while lines.len() < knobs.size.max(3) {
+        let time = rng.gen_range(1..scale / 1000);
+        let velocity = [0; 3].map(|_| rng.gen_range(-300..=300));
+        if velocity == speed || !times.insert(time) {
+            continue;
+        }
+        let position = [0, 1, 2].map(|k| rock[k] + (speed[k] - velocity[k]) * time);
+        lines.push(format!(
+            "{}, {}, {} @ {}, {}, {}",
+            position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+        ));
+    }
+
+    let part_two = rock.iter().sum::<i128>();
+    Generated::new(lines).with_answers(None, Some(part_two))
+}
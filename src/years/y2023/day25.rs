@@ -0,0 +1,163 @@
+use crate::utils::generate::{random_word, Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::utils::input_process::input_to_lines;
+
+// The three wires are the only cut that small, so a max flow of exactly 3
+// from one component to another crosses them. Unit capacities both ways,
+// augmenting paths found breadth first.
+const CUT: usize = 3;
+
+// How many components `source` still reaches once the flow to `sink` is
+// maxed out, None when that flow is not CUT.
+fn cut_side(neighbours: &[Vec<usize>], source: usize, sink: usize) -> Option<usize> {
+    let mut flow: HashMap<(usize, usize), i32> = HashMap::new();
+    for paths in 0..=CUT {
+        let mut previous = vec![None; neighbours.len()];
+        previous[source] = Some(source);
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &next in neighbours[node].iter() {
+                let residual = 1 - flow.get(&(node, next)).unwrap_or(&0);
+                if residual > 0 && previous[next].is_none() {
+                    previous[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if previous[sink].is_none() {
+            let side = previous.iter().filter(|node| node.is_some()).count();
+            return (paths == CUT).then_some(side);
+        }
+        let mut node = sink;
+        while node != source {
+            let before = previous[node].expect("Err: broken path");
+            *flow.entry((before, node)).or_insert(0) += 1;
+            *flow.entry((node, before)).or_insert(0) -= 1;
+            node = before;
+        }
+    }
+    None
+}
+
+pub fn process_lines(lines: &[String]) -> usize {
+    let mut indices: HashMap<&str, usize> = HashMap::new();
+    let mut neighbours: Vec<Vec<usize>> = vec![];
+    let mut index = |name| {
+        let next = indices.len();
+        *indices.entry(name).or_insert_with(|| {
+            neighbours.push(vec![]);
+            next
+        })
+    };
+    let mut wires = vec![];
+    for line in lines {
+        let (source, destinations) = line.split_once(": ").expect("Err: not splittable");
+        for destination in destinations.split(' ') {
+            wires.push((index(source), index(destination)));
+        }
+    }
+    for (a, b) in wires {
+        neighbours[a].push(b);
+        neighbours[b].push(a);
+    }
+
+    let count = neighbours.len();
+    (1..count)
+        .find_map(|sink| cut_side(&neighbours, 0, sink))
+        .map(|side| side * (count - side))
+        .expect("Err: no three wire cut")
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 25, input);
+    reporter.solve(["one"], || {
+        let data = input_to_lines(input);
+        [Ok(process_lines(&data).to_string())]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 25)), Format::Table);
+}
+
+// Two clusters of about size / 2 components each, plus 3 wires between them.
+// A cluster wires k to k + 1, k + a and k + b for random a and b, then
+// difficulty * size random wires: cutting it apart takes at least 6 wires,
+// and any component is a few hops from any other.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let half = (knobs.size / 2).max(8);
+    let sizes = [0; 2].map(|_| rng.gen_range(half - half / 4..=half + half / 4).max(8));
+
+    let mut used = HashSet::new();
+    let names = (0..sizes[0] + sizes[1])
+        .map(|_| loop {
+            let name = random_word(rng, b"abcdefghijklmnopqrstuvwxyz", 3);
+            if used.insert(name.clone()) {
+                break name;
+            }
+        })
+        .collect_vec();
+
+    // Ordered sets and maps, so that a seed always gives the same input.
+    let mut wires = BTreeSet::new();
+    let offsets = [0, sizes[0]];
+    for (size, offset) in sizes.iter().zip(offsets) {
+        // Below size / 2, so that all three steps give distinct wires.
+        let mut steps = vec![1];
+        while steps.len() < 3 {
+            let step = rng.gen_range(2..size.div_ceil(2));
+            if !steps.contains(&step) {
+                steps.push(step);
+            }
+        }
+        for k in 0..*size {
+            for step in steps.iter() {
+                let (a, b) = (offset + k, offset + (k + step) % size);
+                wires.insert((a.min(b), a.max(b)));
+            }
+        }
+        for _ in 0..knobs.difficulty * size {
+            let (a, b) = (rng.gen_range(0..*size), rng.gen_range(0..*size));
+            if a != b {
+                wires.insert((offset + a.min(b), offset + a.max(b)));
+            }
+        }
+    }
+    let mut cut = BTreeSet::new();
+    while cut.len() < 3 {
+        cut.insert((
+            rng.gen_range(0..sizes[0]),
+            sizes[0] + rng.gen_range(0..sizes[1]),
+        ));
+    }
+    wires.extend(cut);
+
+    // Each wire listed once, from either end.
+    let mut listed: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (a, b) in wires {
+        let (from, to) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        listed.entry(from).or_default().push(to);
+    }
+    let mut lines = listed
+        .iter()
+        .map(|(from, to)| {
+            format!(
+                "{}: {}",
+                names[*from],
+                to.iter().map(|to| &names[*to]).join(" ")
+            )
+        })
+        .collect_vec();
+    lines.shuffle(rng);
+
+    Generated::new(lines).with_answers(Some((sizes[0] * sizes[1]) as i128), None)
+}
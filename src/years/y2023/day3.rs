@@ -0,0 +1,266 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+pub struct Number {
+    value: u64,
+    i_pos: usize,
+    // First and last column, inclusive.
+    span: (usize, usize),
+    symbols: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    ch: char,
+    i_pos: usize,
+    j_pos: usize,
+    numbers: Vec<usize>,
+}
+
+// Bipartite graph: numbers and symbols point at each other by index.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+}
+
+impl Number {
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn row(&self) -> usize {
+        self.i_pos
+    }
+
+    pub fn span(&self) -> (usize, usize) {
+        self.span
+    }
+}
+
+impl Symbol {
+    pub fn kind(&self) -> char {
+        self.ch
+    }
+
+    // Row and column.
+    pub fn position(&self) -> (usize, usize) {
+        (self.i_pos, self.j_pos)
+    }
+}
+
+fn is_symbol(ch: char) -> bool {
+    !ch.is_ascii_digit() && ch != '.'
+}
+
+impl Schematic {
+    pub fn new(lines: &[String]) -> Schematic {
+        let mut numbers: Vec<Number> = vec![];
+        let mut symbols: Vec<Symbol> = vec![];
+        let mut symbol_at: HashMap<(usize, usize), usize> = HashMap::new();
+
+        // One pass over the grid for both kinds of nodes.
+        for (i_pos, line) in lines.iter().enumerate() {
+            let mut current: Option<Number> = None;
+            for (j_pos, ch) in line.chars().enumerate() {
+                if let Some(digit) = ch.to_digit(10) {
+                    let number = current.get_or_insert(Number {
+                        value: 0,
+                        i_pos,
+                        span: (j_pos, j_pos),
+                        symbols: vec![],
+                    });
+                    number.value = number.value * 10 + digit as u64;
+                    number.span.1 = j_pos;
+                    continue;
+                }
+
+                numbers.extend(current.take());
+                if is_symbol(ch) {
+                    symbol_at.insert((i_pos, j_pos), symbols.len());
+                    symbols.push(Symbol {
+                        ch,
+                        i_pos,
+                        j_pos,
+                        numbers: vec![],
+                    });
+                }
+            }
+            numbers.extend(current.take());
+        }
+
+        // Edges, from the border of each number.
+        for (id, number) in numbers.iter_mut().enumerate() {
+            let (start, end) = number.span;
+            let rows = number.i_pos.saturating_sub(1)..=number.i_pos + 1;
+            for i_pos in rows {
+                for j_pos in start.saturating_sub(1)..=end + 1 {
+                    if let Some(symbol) = symbol_at.get(&(i_pos, j_pos)) {
+                        number.symbols.push(*symbol);
+                        symbols[*symbol].numbers.push(id);
+                    }
+                }
+            }
+        }
+
+        Schematic { numbers, symbols }
+    }
+
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .filter(|number| !number.symbols.is_empty())
+    }
+
+    pub fn lonely_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .filter(|number| number.symbols.is_empty())
+    }
+
+    // Symbols of any kind touching exactly n numbers.
+    pub fn gears(&self, n: usize) -> impl Iterator<Item = &Symbol> {
+        self.symbols
+            .iter()
+            .filter(move |symbol| symbol.numbers.len() == n)
+    }
+
+    pub fn ratio(&self, symbol: &Symbol) -> u64 {
+        symbol
+            .numbers
+            .iter()
+            .map(|number| self.numbers[*number].value)
+            .product()
+    }
+
+    pub fn ratios_by_kind(&self, n: usize) -> HashMap<char, u64> {
+        let mut ratios = HashMap::new();
+        for gear in self.gears(n) {
+            *ratios.entry(gear.ch).or_insert(0) += self.ratio(gear);
+        }
+        ratios
+    }
+}
+
+fn day3(input: &str) -> (u64, u64) {
+    let fpath = Path::new(input);
+    let file = File::open(fpath).unwrap();
+    let lines = io::BufReader::new(file)
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let schematic = Schematic::new(&lines);
+
+    let part_one = schematic.part_numbers().map(|number| number.value).sum();
+    let part_two = *schematic.ratios_by_kind(2).get(&'*').unwrap_or(&0);
+    (part_one, part_two)
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 3, input);
+    reporter.solve(["one", "two"], || {
+        let (part_one, part_two) = day3(input);
+        [Ok(part_one.to_string()), Ok(part_two.to_string())]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 3)), Format::Table);
+}
+
+fn random_number<R: Rng>(rng: &mut R, digits: u32) -> u64 {
+    rng.gen_range(10_u64.pow(digits - 1).max(1)..10_u64.pow(digits))
+}
+
+fn put(patch: &mut [[char; 6]; 3], i: usize, j: usize, number: u64) {
+    for (k, ch) in number.to_string().chars().enumerate() {
+        patch[i][j + k] = ch;
+    }
+}
+
+// A size x size board of 3x6 patches, with empty rows and columns in between
+// so that patches never see each other. difficulty adds stars touching three
+// numbers and lone symbols.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    const SYMBOLS: &[u8] = b"#$%&*+-/=@";
+    let side = knobs.size.max(1);
+    let mut grid = vec![vec!['.'; side * 7]; side * 4];
+    let (mut part_one, mut part_two) = (0, 0);
+
+    for pi in 0..side {
+        for pj in 0..side {
+            let mut patch = [['.'; 6]; 3];
+            let kinds = if knobs.difficulty > 0 { 5 } else { 3 };
+            match rng.gen_range(0..kinds) {
+                // Lonely number.
+                0 => {
+                    let digits = rng.gen_range(1..=3);
+                    let number = random_number(rng, digits);
+                    put(
+                        &mut patch,
+                        1,
+                        rng.gen_range(0..=6 - digits as usize),
+                        number,
+                    );
+                }
+                // Part number, with a symbol somewhere on its border.
+                1 => {
+                    let digits = rng.gen_range(1..=3);
+                    let number = random_number(rng, digits);
+                    let start = rng.gen_range(1..=5 - digits as usize);
+                    put(&mut patch, 1, start, number);
+                    let i = [0, 2][rng.gen_range(0..2)];
+                    let j = rng.gen_range(start - 1..=start + digits as usize);
+                    patch[i][j] = SYMBOLS[rng.gen_range(0..SYMBOLS.len())] as char;
+                    part_one += number;
+                }
+                // Gear, "a*b".
+                2 => {
+                    let (left, right) = (rng.gen_range(1..=2), rng.gen_range(1..=3));
+                    let (a, b) = (random_number(rng, left), random_number(rng, right));
+                    put(&mut patch, 1, 0, a);
+                    patch[1][left as usize] = '*';
+                    put(&mut patch, 1, left as usize + 1, b);
+                    part_one += a + b;
+                    part_two += a * b;
+                }
+                // Star touching three numbers, not a gear.
+                3 => {
+                    let (a, b, c) = (
+                        random_number(rng, 2),
+                        random_number(rng, 2),
+                        random_number(rng, 2),
+                    );
+                    put(&mut patch, 1, 0, a);
+                    patch[1][2] = '*';
+                    put(&mut patch, 1, 3, b);
+                    put(&mut patch, 0, 2, c);
+                    part_one += a + b + c;
+                }
+                // Lone symbol.
+                _ => {
+                    let (i, j) = (rng.gen_range(0..3), rng.gen_range(0..6));
+                    patch[i][j] = SYMBOLS[rng.gen_range(0..SYMBOLS.len())] as char;
+                }
+            }
+
+            for (i, row) in patch.iter().enumerate() {
+                for (j, ch) in row.iter().enumerate() {
+                    grid[pi * 4 + i][pj * 7 + j] = *ch;
+                }
+            }
+        }
+    }
+
+    let lines = grid.iter().map(|row| row.iter().collect()).collect();
+    Generated::new(lines).with_answers(Some(part_one as i128), Some(part_two as i128))
+}
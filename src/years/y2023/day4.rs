@@ -0,0 +1,167 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+// Card numbers are below 100, one bit each.
+#[derive(Debug, Clone, Copy)]
+struct Data {
+    winning: u128,
+    mine: u128,
+}
+
+impl Data {
+    fn matches(&self) -> u32 {
+        (self.winning & self.mine).count_ones()
+    }
+}
+
+// Card (1-based) at which the copy count no longer fits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CopyOverflow {
+    card: usize,
+}
+
+fn to_bitset(numbers: &str) -> Result<u128, String> {
+    numbers
+        .split(' ')
+        .filter_map(|number| number.parse::<u32>().ok())
+        .try_fold(0, |acc, number| match number < 128 {
+            true => Ok(acc | (1 << number)),
+            false => Err(format!("number {number} out of bitset")),
+        })
+}
+
+// A card is worth 2^(matches - 1), up to 2^127. None when the sum no
+// longer fits.
+fn points(matches: u32) -> Option<u128> {
+    match matches {
+        0 => Some(0),
+        matches => 1_u128.checked_shl(matches - 1),
+    }
+}
+
+fn process_data_part_one(datas: &[Data]) -> Option<u128> {
+    datas.iter().try_fold(0_u128, |total, data| {
+        total.checked_add(points(data.matches())?)
+    })
+}
+
+// Difference array: a card adds its copies to the next `matches` cards,
+// they are taken back out where that range expires.
+fn process_data_part_two(datas: &[Data]) -> Result<u128, CopyOverflow> {
+    let len = datas.len();
+    let mut expiring = vec![0_u128; len + 1];
+    let mut extra: u128 = 0;
+    let mut total: u128 = 0;
+
+    for (idx, data) in datas.iter().enumerate() {
+        let overflow = CopyOverflow { card: idx + 1 };
+        extra -= expiring[idx];
+        let copies = extra.checked_add(1).ok_or(overflow)?;
+        total = total.checked_add(copies).ok_or(overflow)?;
+
+        let matches = data.matches() as usize;
+        if matches > 0 {
+            let end = (idx + 1 + matches).min(len);
+            extra = extra.checked_add(copies).ok_or(overflow)?;
+            expiring[end] = expiring[end].checked_add(copies).ok_or(overflow)?;
+        }
+    }
+
+    Ok(total)
+}
+
+fn day4(input: &str) -> Result<(Option<u128>, Result<u128, CopyOverflow>), String> {
+    let fpath = Path::new(input);
+    let file = File::open(fpath).unwrap();
+    let lines = io::BufReader::new(file).lines();
+
+    let lines: Vec<Data> = lines
+        .into_iter()
+        .map(|f| f.expect("Err: No line"))
+        .map(|line| {
+            let data = (line.split(':').collect::<Vec<&str>>())[1];
+            let data_strings: Vec<&str> = data.split(" | ").collect();
+            Ok(Data {
+                winning: to_bitset(data_strings[0])?,
+                mine: to_bitset(data_strings[1])?,
+            })
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok((process_data_part_one(&lines), process_data_part_two(&lines)))
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 4, input);
+    reporter.solve(["one", "two"], || {
+        let (part_one, part_two) = match day4(input) {
+            Ok(parts) => parts,
+            Err(message) => return [Err(message.clone()), Err(message)],
+        };
+        let part_one = part_one
+            .map(|part_one| part_one.to_string())
+            .ok_or("points overflow".to_string());
+        let part_two = part_two
+            .map(|part_two| part_two.to_string())
+            .map_err(|CopyOverflow { card }| format!("overflows at card {card}"));
+        [part_one, part_two]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 4)), Format::Table);
+}
+
+// size cards, 10 winning and 25 owned numbers. difficulty raises the number
+// of matches, large stacks of cards then overflow part two.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let count = knobs.size.max(1);
+    let most = (2 + 2 * knobs.difficulty).min(10);
+    let mut lines = vec![];
+    let mut matches = vec![];
+
+    for card in 1..=count {
+        let mut numbers = (1..100).collect_vec();
+        numbers.shuffle(rng);
+        let (winning, others) = numbers.split_at(10);
+        let hits = rng.gen_range(0..=most.min(count - card));
+        let mut mine = winning[..hits].to_vec();
+        mine.extend_from_slice(&others[..25 - hits]);
+        mine.shuffle(rng);
+
+        let spaced = |numbers: &[u32]| numbers.iter().map(|n| format!("{n:>2}")).join(" ");
+        lines.push(format!(
+            "Card {card:>3}: {} | {}",
+            spaced(winning),
+            spaced(&mine)
+        ));
+        matches.push(hits);
+    }
+
+    let part_one = matches.iter().try_fold(0_u128, |total, hits| {
+        total.checked_add(points(*hits as u32)?)
+    });
+
+    let mut copies = vec![1_u128; count];
+    let mut part_two = Some(0_u128);
+    for (card, hits) in matches.iter().enumerate() {
+        part_two = part_two.and_then(|total| total.checked_add(copies[card]));
+        for next in card + 1..=card + hits {
+            copies[next] = copies[next].saturating_add(copies[card]);
+        }
+    }
+
+    Generated::new(lines).with_answers(
+        part_one.and_then(|total| i128::try_from(total).ok()),
+        part_two.and_then(|total| i128::try_from(total).ok()),
+    )
+}
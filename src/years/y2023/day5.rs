@@ -0,0 +1,221 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::input_process::{input_to_lines, line_to_ints};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub range: Range<i64>,
+    pub back: i64,
+}
+
+impl Map {
+    pub fn get_back(&self, seed: i64) -> i64 {
+        seed + self.back
+    }
+}
+
+// Through one layer: a value moves with the first map whose source holds it.
+fn forward(value: i64, maps: &[Map]) -> i64 {
+    maps.iter()
+        .find(|map| map.range.contains(&(value - map.back)))
+        .map_or(value, |map| value - map.back)
+}
+
+// Through one layer, range by range: each map takes the pieces its source
+// covers and not taken yet by an earlier map, the rest moves as is.
+fn forward_ranges(ranges: Vec<Range<i64>>, maps: &[Map]) -> Vec<Range<i64>> {
+    let mut moved = vec![];
+    let mut pending = ranges;
+    for map in maps {
+        let source = (map.range.start + map.back)..(map.range.end + map.back);
+        let mut kept = vec![];
+        for range in pending {
+            let start = range.start.max(source.start);
+            let end = range.end.min(source.end);
+            if start >= end {
+                kept.push(range);
+                continue;
+            }
+            moved.push((start - map.back)..(end - map.back));
+            if range.start < start {
+                kept.push(range.start..start);
+            }
+            if end < range.end {
+                kept.push(end..range.end);
+            }
+        }
+        pending = kept;
+    }
+    moved.extend(pending);
+    moved
+}
+
+// Lowest location over all the seed ranges, None without any seed.
+pub fn process_seeds_range(seeds_range: Vec<Range<i64>>, map_vectors: &[Vec<Map>]) -> Option<i64> {
+    let seeds_range = seeds_range
+        .into_iter()
+        .filter(|range| !range.is_empty())
+        .collect();
+    map_vectors
+        .iter()
+        .fold(seeds_range, |ranges, maps| forward_ranges(ranges, maps))
+        .iter()
+        .map(|range| range.start)
+        .min()
+}
+
+// Forward, seed by seed.
+pub fn process_seeds(seeds: &[i64], map_vectors: &[Vec<Map>]) -> Option<i64> {
+    seeds
+        .iter()
+        .map(|seed| {
+            map_vectors
+                .iter()
+                .fold(*seed, |value, maps| forward(value, maps))
+        })
+        .min()
+}
+
+pub fn seed_ranges(seeds: &[i64]) -> Vec<Range<i64>> {
+    seeds
+        .chunks(2)
+        .map(|chunks| Range {
+            start: chunks[0],
+            end: chunks[0] + chunks[1],
+        })
+        .collect()
+}
+
+pub fn format_data(data: &mut Vec<String>) -> (Vec<Vec<Map>>, Vec<i64>) {
+    let seeds = line_to_ints::<i64>(&data.remove(0), ' ');
+
+    let mut mapppings: Vec<Vec<_>> = Vec::new();
+    for line in data {
+        if line.is_empty() {
+            mapppings.push(Vec::new());
+            continue;
+        }
+
+        let ch = line.chars().next().expect("Err: no first char");
+
+        if ch.is_alphabetic() {
+            continue;
+        }
+
+        let current_mappings = mapppings.last_mut().expect("Err: empty mappings");
+        let data = line_to_ints::<i64>(line, ' ');
+        current_mappings.push(Map {
+            range: Range {
+                start: data[0],
+                end: data[0] + data[2],
+            },
+            back: data[1] - data[0],
+        });
+    }
+
+    (mapppings, seeds)
+}
+
+fn day5(input: &str) -> (Option<i64>, Option<i64>) {
+    let mut data = input_to_lines(input);
+    let (mapppings, seeds) = format_data(&mut data);
+    (
+        process_seeds(&seeds, &mapppings),
+        process_seeds_range(seed_ranges(&seeds), &mapppings),
+    )
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 5, input);
+    reporter.solve(["one", "two"], || {
+        let (part_one, part_two) = day5(input);
+        [
+            part_one
+                .map(|part_one| part_one.to_string())
+                .ok_or("no seed".to_string()),
+            part_two
+                .map(|part_two| part_two.to_string())
+                .ok_or("no seed in the ranges".to_string()),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 5)), Format::Table);
+}
+
+// size seed ranges over values below 10^(3 + difficulty); each map shuffles
+// 2 + difficulty pieces of that range, some pieces left unmapped.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    const NAMES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    let top = 10_i64.pow(3 + knobs.difficulty.min(6) as u32);
+
+    let seeds = (0..knobs.size.max(1))
+        .flat_map(|_| {
+            let start = rng.gen_range(0..top);
+            [start, rng.gen_range(1..=(top - start).min(top / 10).max(1))]
+        })
+        .collect_vec();
+    let mut lines = vec![format!("seeds: {}", seeds.iter().join(" "))];
+
+    // Part one reads every number of the seeds line as a seed.
+    let mut values = seeds.clone();
+    for names in NAMES.windows(2) {
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:", names[0], names[1]));
+
+        let mut cuts = (0..1 + knobs.difficulty)
+            .map(|_| rng.gen_range(1..top))
+            .chain([0, top])
+            .collect_vec();
+        cuts.sort();
+        cuts.dedup();
+        let mut pieces = cuts
+            .windows(2)
+            .map(|pair| (pair[0], pair[1] - pair[0]))
+            .collect_vec();
+        pieces.shuffle(rng);
+
+        // The pieces laid back to back in shuffled order, as
+        // (destination, source, length). Some keep their place instead.
+        let mut maps = vec![];
+        let mut destination = 0;
+        for (source, len) in pieces {
+            if rng.gen_bool(0.8) {
+                maps.push((destination, source, len));
+            }
+            destination += len;
+        }
+        for (destination, source, len) in maps.iter() {
+            lines.push(format!("{destination} {source} {len}"));
+        }
+
+        for value in values.iter_mut() {
+            if let Some((destination, source, _)) = maps
+                .iter()
+                .find(|(_, source, len)| (*source..source + len).contains(value))
+            {
+                *value += destination - source;
+            }
+        }
+    }
+
+    let part_one = values.iter().min().copied().map(i128::from);
+    Generated::new(lines).with_answers(part_one, None)
+}
@@ -0,0 +1,134 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::input_process::{input_to_lines, line_to_ints};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Big numbers: the kerned race of part two has every digit of the input.
+#[derive(Debug, Clone)]
+pub struct Race {
+    time: BigUint,
+    distance: BigUint,
+}
+
+impl Race {
+    pub fn new(time: BigUint, distance: BigUint) -> Race {
+        Race { time, distance }
+    }
+}
+
+fn format_data(data: &[String]) -> Vec<Race> {
+    let times: Vec<_> = line_to_ints::<BigUint>(&data[0], ' ');
+    let distances: Vec<_> = line_to_ints::<BigUint>(&data[1], ' ');
+
+    times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect()
+}
+
+// Whatever the label width, digits after the colon are one number.
+fn kerned_number(line: &str) -> BigUint {
+    let (_, digits) = line.split_once(':').expect("Err: no label");
+    digits
+        .replace(' ', "")
+        .parse::<BigUint>()
+        .expect("Err: number parse")
+}
+
+fn format_data_part_two(data: &[String]) -> Race {
+    Race {
+        time: kerned_number(&data[0]),
+        distance: kerned_number(&data[1]),
+    }
+}
+
+fn beats(race: &Race, hold: &BigUint) -> bool {
+    hold * (&race.time - hold) > race.distance
+}
+
+// Holds h with h * (time - h) > distance sit strictly between the roots
+// (time +- sqrt(time^2 - 4 distance)) / 2. The integer root gets us within
+// one of the first winning hold, ties on the distance do not count.
+pub fn process_race(race: &Race) -> BigUint {
+    let square = &race.time * &race.time;
+    let four_distance = BigUint::from(4_u32) * &race.distance;
+    if square <= four_distance {
+        return BigUint::zero();
+    }
+
+    let one = BigUint::one();
+    let half = &race.time / 2_u32;
+    let root = (square - four_distance).sqrt();
+    let mut low = (&race.time - root) / 2_u32;
+    while low <= half && !beats(race, &low) {
+        low += 1_u32;
+    }
+    while !low.is_zero() && beats(race, &(&low - &one)) {
+        low -= 1_u32;
+    }
+
+    if low > half {
+        return BigUint::zero();
+    }
+    &race.time - low * 2_u32 + one
+}
+
+fn process_races(races: Vec<Race>) -> BigUint {
+    races.iter().map(process_race).product()
+}
+
+fn day6(input: &str) -> (BigUint, BigUint) {
+    let data = input_to_lines(input);
+    let races: Vec<Race> = format_data(&data);
+    let result_part_one = process_races(races);
+
+    let race = format_data_part_two(&data);
+    let result_part_two = process_race(&race);
+    (result_part_one, result_part_two)
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 6, input);
+    reporter.solve(["one", "two"], || {
+        let (part_one, part_two) = day6(input);
+        [Ok(part_one.to_string()), Ok(part_two.to_string())]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 6)), Format::Table);
+}
+
+// size races of up to 2 + difficulty digits. Each record is beaten by
+// exactly time - 2 * held - 1 holds.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let top = 10_u128.pow(2 + knobs.difficulty.min(8) as u32);
+    let mut times = vec![];
+    let mut distances = vec![];
+    let mut part_one = Some(1_u128);
+
+    for _ in 0..knobs.size.max(1) {
+        let time = rng.gen_range(3..top.max(4));
+        let held = rng.gen_range(0..=(time - 2) / 2);
+        times.push(time);
+        distances.push(held * (time - held));
+        part_one = part_one.and_then(|product| product.checked_mul(time - 2 * held - 1));
+    }
+
+    let columns = |label: &str, values: &[u128]| {
+        let values = values.iter().map(|value| format!("{value:>5}")).join(" ");
+        format!("{label:<9} {values}")
+    };
+    let lines = vec![columns("Time:", &times), columns("Distance:", &distances)];
+    Generated::new(lines).with_answers(
+        part_one.and_then(|product| i128::try_from(product).ok()),
+        None,
+    )
+}
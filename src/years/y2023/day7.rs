@@ -0,0 +1,176 @@
+use crate::utils::generate::{random_word, Generated, Knobs};
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::utils::input_process::input_to_lines;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Cards {
+    hand: String,
+    bid: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Rules {
+    // Weakest card first.
+    order: Vec<char>,
+    wildcards: Vec<char>,
+}
+
+impl Rules {
+    pub fn new(order: &str, wildcards: &str) -> Rules {
+        Rules {
+            order: order.chars().collect(),
+            wildcards: wildcards.chars().collect(),
+        }
+    }
+
+    fn get_card_value(&self, ch: char) -> usize {
+        self.order
+            .iter()
+            .position(|card| *card == ch)
+            .unwrap_or_else(|| panic!("Err: unmanaged char {ch}"))
+    }
+
+    // Multiplicities, biggest first: [3, 2] is a full house, [1, 1, 1, 1, 1]
+    // a high card. Wildcards all join the biggest set.
+    fn get_category(&self, hand: &str) -> Vec<usize> {
+        let mut sets: HashMap<char, usize> = HashMap::new();
+        let mut wilds = 0;
+        for ch in hand.chars() {
+            if self.wildcards.contains(&ch) {
+                wilds += 1;
+            } else {
+                *sets.entry(ch).or_insert(0) += 1;
+            }
+        }
+
+        let mut category = sets.into_values().sorted().rev().collect_vec();
+        match category.first_mut() {
+            Some(biggest) => *biggest += wilds,
+            None => category.push(wilds),
+        }
+        category
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ranked {
+    hand: String,
+    bid: u64,
+    category: Vec<usize>,
+    rank: usize,
+}
+
+impl Ranked {
+    pub fn hand(&self) -> &str {
+        &self.hand
+    }
+
+    pub fn category(&self) -> &[usize] {
+        &self.category
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
+
+pub fn format_data(lines: &[String]) -> Vec<Cards> {
+    lines
+        .iter()
+        .map(|line| {
+            let parts = line.split(' ').collect::<Vec<&str>>();
+            Cards {
+                hand: parts[0].to_string(),
+                bid: parts[1].parse::<u64>().expect("Err: could not parse u64"),
+            }
+        })
+        .collect()
+}
+
+// Category first, then card by card.
+pub fn rank_cards(cards: &[Cards], rules: &Rules) -> Vec<Ranked> {
+    cards
+        .iter()
+        .map(|card| {
+            let category = rules.get_category(&card.hand);
+            let values = card
+                .hand
+                .chars()
+                .map(|ch| rules.get_card_value(ch))
+                .collect_vec();
+            (category, values, card)
+        })
+        .sorted_by(|lhs, rhs| (&lhs.0, &lhs.1).cmp(&(&rhs.0, &rhs.1)))
+        .enumerate()
+        .map(|(i, (category, _, card))| Ranked {
+            hand: card.hand.clone(),
+            bid: card.bid,
+            category,
+            rank: i + 1,
+        })
+        .collect()
+}
+
+pub fn process_cards(ranked: &[Ranked]) -> u64 {
+    ranked
+        .iter()
+        .map(|ranked| ranked.rank as u64 * ranked.bid)
+        .sum::<u64>()
+}
+
+pub fn report(ranked: &[Ranked]) {
+    for Ranked {
+        hand,
+        bid,
+        category,
+        rank,
+    } in ranked
+    {
+        let category = category.iter().join("-");
+        println!("{rank:>5} {hand} {category:<10} {bid}");
+    }
+}
+
+fn day7(input: &str) -> (u64, u64) {
+    let data = input_to_lines(input);
+    let cards = format_data(&data);
+    let part_one = rank_cards(&cards, &Rules::new("23456789TJQKA", ""));
+    let part_two = rank_cards(&cards, &Rules::new("J23456789TQKA", "J"));
+    (process_cards(&part_one), process_cards(&part_two))
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 7, input);
+    reporter.solve(["one", "two"], || {
+        let (part_one, part_two) = day7(input);
+        [Ok(part_one.to_string()), Ok(part_two.to_string())]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    report::print(&solve(&years::input_path(2023, 7)), Format::Table);
+}
+
+// size hands. Higher difficulty draws from fewer labels, so more pairs, sets
+// and jokers.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    const LABELS: &[u8] = b"23456789TJQKA";
+    let labels = &LABELS[LABELS
+        .len()
+        .saturating_sub(13 / (1 + knobs.difficulty))
+        .min(8)..];
+    let lines = (0..knobs.size.max(1))
+        .map(|_| {
+            let hand = random_word(rng, labels, 5);
+            format!("{hand} {}", rng.gen_range(1..1000))
+        })
+        .collect();
+    Generated::new(lines)
+}
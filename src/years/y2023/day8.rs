@@ -0,0 +1,303 @@
+use crate::utils::generate::{is_prime, random_word, Generated, Knobs};
+use crate::utils::input_process::input_to_lines;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+struct Direction {
+    left: String,
+    right: String,
+}
+
+// Where a ghost stands on Z nodes. The walk is keyed on (node, instruction
+// index); once a key repeats at time cycle_start + cycle_len, it loops.
+#[derive(Debug, Clone)]
+struct Ghost {
+    start: String,
+    // Z times before the loop.
+    transient: Vec<u128>,
+    cycle_start: u128,
+    cycle_len: u128,
+    // Z times inside the first loop, each one repeats every cycle_len.
+    cycle: Vec<u128>,
+}
+
+impl Ghost {
+    fn is_on_z(&self, time: u128) -> bool {
+        if time < self.cycle_start {
+            return self.transient.contains(&time);
+        }
+        let offset = (time - self.cycle_start) % self.cycle_len;
+        self.cycle.contains(&(self.cycle_start + offset))
+    }
+
+    fn first_z(&self) -> Option<u128> {
+        self.transient.first().or(self.cycle.first()).copied()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum NoMeeting {
+    // No node ends with A.
+    NoGhost,
+    // This ghost never stands on a Z node.
+    NeverOnZ(String),
+    // No residue combination of the loops satisfies all congruences.
+    Incompatible,
+}
+
+fn analyze_ghost(
+    instructions: &[char],
+    maps: &HashMap<String, Direction>,
+    start: &str,
+    is_goal: impl Fn(&str) -> bool,
+) -> Ghost {
+    let loop_size = instructions.len();
+    let mut seen: HashMap<(String, usize), u128> = HashMap::new();
+    let mut z_times = vec![];
+    let mut current_value = start.to_string();
+    let mut time: u128 = 0;
+
+    loop {
+        let idx = (time % loop_size as u128) as usize;
+        if let Some(first) = seen.insert((current_value.clone(), idx), time) {
+            let (transient, cycle) = z_times.iter().partition(|z_time| **z_time < first);
+            return Ghost {
+                start: start.to_string(),
+                transient,
+                cycle_start: first,
+                cycle_len: time - first,
+                cycle,
+            };
+        }
+
+        if is_goal(&current_value) {
+            z_times.push(time);
+        }
+
+        current_value = match instructions[idx] {
+            'L' => maps[&current_value].left.clone(),
+            'R' => maps[&current_value].right.clone(),
+            _ => panic!("Err no instruction given"),
+        };
+        time += 1;
+    }
+}
+
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+// Least common multiple of all, 1 for none.
+pub fn lcm(values: &[i128]) -> i128 {
+    values.iter().fold(1, |acc, value| {
+        let (g, _, _) = extended_gcd(acc, *value);
+        (acc / g * value).abs()
+    })
+}
+
+// x = a1 mod m1 and x = a2 mod m2, moduli need not be coprime.
+pub fn crt_pair((a1, m1): (i128, i128), (a2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, p, _) = extended_gcd(m1, m2);
+    if (a2 - a1) % g != 0 {
+        return None;
+    }
+    let modulus = m1 / g * m2;
+    let step = ((a2 - a1) / g * p).rem_euclid(m2 / g);
+    Some(((a1 + m1 * step).rem_euclid(modulus), modulus))
+}
+
+fn earliest_meeting(ghosts: &[Ghost]) -> Result<u128, NoMeeting> {
+    if let Some(ghost) = ghosts.iter().find(|ghost| ghost.first_z().is_none()) {
+        return Err(NoMeeting::NeverOnZ(ghost.start.clone()));
+    }
+
+    // Before the last loop starts, the slowest ghost is still in its transient.
+    let Some(slowest) = ghosts.iter().max_by_key(|ghost| ghost.cycle_start) else {
+        return Err(NoMeeting::NoGhost);
+    };
+    if let Some(time) = slowest
+        .transient
+        .iter()
+        .find(|time| ghosts.iter().all(|ghost| ghost.is_on_z(**time)))
+    {
+        return Ok(*time);
+    }
+
+    // After it, everybody loops. Ghost by ghost, the residues that still work
+    // for all ghosts so far; they share one modulus, so they stay fewer than it.
+    let floor = slowest.cycle_start as i128;
+    let mut solutions: Vec<(i128, i128)> = vec![(0, 1)];
+    for ghost in ghosts {
+        solutions = solutions
+            .iter()
+            .cartesian_product(&ghost.cycle)
+            .filter_map(|(solution, time)| {
+                crt_pair(*solution, (*time as i128, ghost.cycle_len as i128))
+            })
+            .unique()
+            .collect();
+    }
+    solutions
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let laps = ((floor - residue).max(0) + modulus - 1) / modulus;
+            (residue + laps * modulus) as u128
+        })
+        .min()
+        .ok_or(NoMeeting::Incompatible)
+}
+
+fn day8(input: &str) -> (Result<u128, String>, Result<u128, NoMeeting>) {
+    let mut data = input_to_lines(input);
+    let instructions = &data.remove(0);
+    data.remove(0);
+
+    let mut maps: HashMap<String, Direction> = HashMap::new();
+
+    for line in data.iter() {
+        let alpha_values: String = line.chars().filter(|ch| ch.is_alphanumeric()).collect();
+        maps.insert(
+            alpha_values[0..3].to_string(),
+            Direction {
+                left: alpha_values[3..6].to_string(),
+                right: alpha_values[6..9].to_string(),
+            },
+        );
+    }
+
+    let instructions = instructions.chars().collect_vec();
+    let part_one = if maps.contains_key("AAA") {
+        analyze_ghost(&instructions, &maps, "AAA", |node| node == "ZZZ")
+            .first_z()
+            .ok_or("ZZZ not reachable from AAA".to_string())
+    } else {
+        Err("no AAA node".to_string())
+    };
+
+    let ghosts = maps
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .sorted()
+        .map(|node| analyze_ghost(&instructions, &maps, node, |node| node.ends_with('Z')))
+        .collect_vec();
+
+    (part_one, earliest_meeting(&ghosts))
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 8, input);
+    reporter.solve(["one", "two"], || {
+        let (part_one, part_two) = day8(input);
+        [
+            part_one.map(|part_one| part_one.to_string()),
+            part_two
+                .map(|part_two| part_two.to_string())
+                .map_err(|error| format!("{error:?}")),
+        ]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    // Did not like this one, the description misleads.
+    report::print(&solve(&years::input_path(2023, 8)), Format::Table);
+}
+
+// 1 + difficulty ghosts, each walking a loop of a prime length between size
+// and twice size, AAA's loop ends on ZZZ. Both branches of a node agree, the
+// instructions are a decoy.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    const LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+    let low = knobs.size.max(2) as u64;
+    let ghosts = 1 + knobs.difficulty.min(5);
+
+    let mut lengths: Vec<u64> = vec![];
+    while lengths.len() < ghosts {
+        let length = rng.gen_range(low..=2 * low + 10);
+        if is_prime(length) && !lengths.contains(&length) {
+            lengths.push(length);
+        }
+    }
+
+    let mut used = HashSet::new();
+    let mut name = |rng: &mut StdRng, last: &str| loop {
+        let name = random_word(rng, LETTERS, 2) + last;
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let instructions = (0..rng.gen_range(2..=low as usize))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+    let mut nodes = vec![];
+    for (ghost, length) in lengths.iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (name(rng, "A"), name(rng, "Z")),
+        };
+        let mut cycle = (1..*length)
+            .map(|_| {
+                let last = LETTERS[rng.gen_range(0..LETTERS.len())] as char;
+                name(rng, &last.to_string())
+            })
+            .collect_vec();
+        cycle.push(end);
+
+        nodes.push((start, cycle[0].clone()));
+        for (k, node) in cycle.iter().enumerate() {
+            nodes.push((node.clone(), cycle[(k + 1) % cycle.len()].clone()));
+        }
+    }
+    nodes.shuffle(rng);
+
+    let mut lines = vec![instructions, String::new()];
+    lines.extend(
+        nodes
+            .iter()
+            .map(|(node, next)| format!("{node} = ({next}, {next})")),
+    );
+
+    let part_two = lengths
+        .iter()
+        .map(|length| *length as u128)
+        .product::<u128>();
+    Generated::new(lines).with_answers(Some(lengths[0] as i128), i128::try_from(part_two).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 4 and 6 share 2: the merged modulus is their lcm, not their product.
+    #[test]
+    fn crt_non_coprime() {
+        assert_eq!(crt_pair((2, 4), (4, 6)), Some((10, 12)));
+        assert_eq!(crt_pair((3, 6), (0, 9)), Some((9, 18)));
+        // Odd and even at once.
+        assert_eq!(crt_pair((1, 4), (2, 6)), None);
+    }
+
+    #[test]
+    fn crt_coprime() {
+        assert_eq!(crt_pair((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt_pair((0, 1), (4, 7)), Some((4, 7)));
+    }
+
+    #[test]
+    fn lcm_of_all() {
+        assert_eq!(lcm(&[4, 6, 10]), 60);
+        assert_eq!(lcm(&[]), 1);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::utils::generate::{Generated, Knobs};
+use crate::utils::input_process::{input_to_lines, line_to_ints};
+use crate::utils::polynomial::Polynomial;
+use crate::utils::report::{self, Format, Outcome, Reporter};
+use crate::years;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+fn extrapolate(values: &[i128], index: i128) -> BigInt {
+    Polynomial::fit(values).evaluate(index)
+}
+
+fn day9(input: &str) -> (BigInt, BigInt) {
+    let data = input_to_lines(input);
+
+    data.iter().fold(
+        (BigInt::zero(), BigInt::zero()),
+        |(mut acc_left, mut acc_right), datum| {
+            let datum_ints = line_to_ints::<i128>(datum, ' ');
+            acc_left += extrapolate(&datum_ints, -1);
+            acc_right += extrapolate(&datum_ints, datum_ints.len() as i128);
+            (acc_left, acc_right)
+        },
+    )
+}
+
+pub fn solve(input: &str) -> Vec<Outcome> {
+    let mut reporter = Reporter::new(2023, 9, input);
+    reporter.solve(["one", "two"], || {
+        let (left, right) = day9(input);
+        [Ok(right.to_string()), Ok(left.to_string())]
+    });
+    reporter.outcomes()
+}
+
+pub fn run() {
+    // Did not like this one, the description misleads.
+    report::print(&solve(&years::input_path(2023, 9)), Format::Table);
+}
+
+// size sequences of 21 values, from polynomials of degree up to
+// 2 + difficulty with small forward differences.
+pub(crate) fn generate(rng: &mut StdRng, knobs: &Knobs) -> Generated {
+    let (mut part_one, mut part_two) = (0, 0);
+    let mut lines = vec![];
+    for _ in 0..knobs.size.max(1) {
+        let degree = rng.gen_range(0..=2 + knobs.difficulty as i128);
+        let polynomial =
+            Polynomial::from_differences((0..=degree).map(|_| rng.gen_range(-9..=9)).collect());
+        let value = |x: i128| {
+            polynomial
+                .evaluate(x)
+                .to_i128()
+                .expect("Err: small differences overflow")
+        };
+
+        lines.push((0..21).map(value).join(" "));
+        part_one += value(21);
+        part_two += value(-1);
+    }
+    Generated::new(lines).with_answers(Some(part_one), Some(part_two))
+}
@@ -0,0 +1,114 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use super::{Animator, Generator, Solver, Visualizer};
+
+pub fn generator(day: u32) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day1::generate,
+        2 => day2::generate,
+        3 => day3::generate,
+        4 => day4::generate,
+        5 => day5::generate,
+        6 => day6::generate,
+        7 => day7::generate,
+        8 => day8::generate,
+        9 => day9::generate,
+        10 => day10::generate,
+        11 => day11::generate,
+        12 => day12::generate,
+        13 => day13::generate,
+        14 => day14::generate,
+        15 => day15::generate,
+        16 => day16::generate,
+        17 => day17::generate,
+        18 => day18::generate,
+        19 => day19::generate,
+        20 => day20::generate,
+        21 => day21::generate,
+        22 => day22::generate,
+        23 => day23::generate,
+        24 => day24::generate,
+        25 => day25::generate,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+pub fn solver(day: u32) -> Option<Solver> {
+    let solver: Solver = match day {
+        1 => day1::solve,
+        2 => day2::solve,
+        3 => day3::solve,
+        4 => day4::solve,
+        5 => day5::solve,
+        6 => day6::solve,
+        7 => day7::solve,
+        8 => day8::solve,
+        9 => day9::solve,
+        10 => day10::solve,
+        11 => day11::solve,
+        12 => day12::solve,
+        13 => day13::solve,
+        14 => day14::solve,
+        15 => day15::solve,
+        16 => day16::solve,
+        17 => day17::solve,
+        18 => day18::solve,
+        19 => day19::solve,
+        20 => day20::solve,
+        21 => day21::solve,
+        22 => day22::solve,
+        23 => day23::solve,
+        24 => day24::solve,
+        25 => day25::solve,
+        _ => return None,
+    };
+    Some(solver)
+}
+
+// Only the days that draw.
+pub fn animator(day: u32) -> Option<Animator> {
+    let animator: Animator = match day {
+        10 => day10::animate,
+        14 => day14::animate,
+        16 => day16::animate,
+        21 => day21::animate,
+        _ => return None,
+    };
+    Some(animator)
+}
+
+pub fn visualizer(day: u32) -> Option<Visualizer> {
+    let visualizer: Visualizer = match day {
+        14 => day14::visualize,
+        16 => day16::visualize,
+        17 => day17::visualize,
+        21 => day21::visualize,
+        _ => return None,
+    };
+    Some(visualizer)
+}
@@ -0,0 +1,67 @@
+use aoc2023::utils::report::Status;
+use aoc2023::years;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Numbered subdirectories, in order.
+fn numbered(directory: &Path, prefix: &str) -> Vec<(u32, PathBuf)> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return vec![];
+    };
+    let mut numbered = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let number = name.strip_prefix(prefix)?.parse().ok()?;
+            Some((number, path))
+        })
+        .collect::<Vec<_>>();
+    numbered.sort();
+    numbered
+}
+
+// Every days/YYYY/dayN/examples/K against its answer.txt: each known answer
+// has an outcome of that part, and that outcome is right.
+#[test]
+fn examples() {
+    let mut checked = 0;
+    let mut failures = vec![];
+    for (year, year_path) in numbered(Path::new("days"), "") {
+        for (day, day_path) in numbered(&year_path, "day") {
+            for (_, fixture) in numbered(&day_path.join("examples"), "") {
+                let solver = years::solver(year, day)
+                    .unwrap_or_else(|| panic!("{year}/day{day} has examples but no solver"));
+                let input = fixture.join("input.txt");
+                let answers = fs::read_to_string(fixture.join("answer.txt"))
+                    .unwrap_or_else(|_| panic!("{} has no answer.txt", fixture.display()));
+                let answer_lines = answers.lines().map(String::from).collect::<Vec<_>>();
+
+                let mut outcomes = solver(input.to_str().expect("Err: path"));
+                for outcome in outcomes.iter_mut() {
+                    outcome.check(&answer_lines);
+                }
+                for (part, expected) in answer_lines.iter().filter_map(|line| line.split_once(' '))
+                {
+                    if expected == "unknown" {
+                        continue;
+                    }
+                    let part = part.trim_start_matches("part_");
+                    match outcomes.iter().find(|outcome| outcome.part == part) {
+                        Some(outcome) if outcome.status == Status::Right => checked += 1,
+                        Some(outcome) => failures.push(format!(
+                            "{}: part {part} {}",
+                            fixture.display(),
+                            outcome.status
+                        )),
+                        None => failures.push(format!(
+                            "{}: part {part} expected {expected}, not answered",
+                            fixture.display()
+                        )),
+                    }
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    assert!(checked > 0, "no example answers found under days/");
+}